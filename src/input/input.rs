@@ -1,33 +1,75 @@
-use std::{fs::File, io::Read, path::Path};
+use std::{env, fmt::Display, fs::File, io::{self, Read}, path::{Path, PathBuf}, str::FromStr};
 
-const INPUT_PATH_HEAD: &str = "C:/Users/cmsdu/repos/advent-of-code-2022/input/";
+use crate::utility::error::ParseError;
 
-pub struct InputParser;
+use super::profile::Profile;
+use super::source::Source;
+use super::store::InputStore;
+
+pub const INPUT_ROOT_ENV_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Clone, Debug, Default)]
+pub struct InputParser {
+    input_root: Option<PathBuf>,
+    store: Option<InputStore>,
+}
 
 impl InputParser {
 
     pub fn new() -> InputParser {
-        InputParser {}
+        InputParser { input_root: None, store: None }
+    }
+
+    pub fn with_input_root<P: Into<PathBuf>>(mut self, input_root: P) -> Self {
+        self.input_root = Some(input_root.into());
+        self
+    }
+
+    // The environment variable wins over the builder, which wins over the crate's own input folder.
+    pub fn get_input_root(&self) -> PathBuf {
+        Self::resolve_input_root(env::var(INPUT_ROOT_ENV_VAR).ok(), self.input_root.as_deref())
     }
 
-    pub fn parse_as_i32(&self, filepath: &str) -> Result<Vec<i32>, String> {
-        self.parse_as_string(filepath)?.iter().enumerate().map(
+    fn resolve_input_root(from_env: Option<String>, from_builder: Option<&Path>) -> PathBuf {
+        match (from_env, from_builder) {
+            (Some(root), _) if !root.is_empty() => PathBuf::from(root),
+            (_, Some(root)) => root.to_path_buf(),
+            _ => Path::new(env!("CARGO_MANIFEST_DIR")).join("input"),
+        }
+    }
+
+    pub fn with_store(mut self, store: InputStore) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    // Without a store from the builder, the input root doubles as the cache, so inputs saved by hand are cache hits.
+    // That store never downloads anything; inputs are only fetched when asked for with the fetch command.
+    pub fn get_store(&self) -> InputStore {
+        match &self.store {
+            Some(store) => store.clone(),
+            None => InputStore::new(self.get_input_root()),
+        }
+    }
+
+    pub fn parse_as_i32(&self, source: impl Into<Source>) -> Result<Vec<i32>, ParseError> {
+        self.parse_as_string(source)?.iter().enumerate().map(
             |(i, line)| line.parse::<i32>()
-                .map_err(|_| format!("Unable to parse line {} to i32.", i)
+                .map_err(|e| ParseError::invalid(i + 1, 1, format!("Unable to parse {:?} to i32: {}", line, e))
             )
         ).collect()
     }
 
-    pub fn parse_as_binary(&self, filepath: &str) -> Result<Vec<u32>, String> {
-        self.parse_as_string(filepath)?.into_iter().map(
-            |line| line.chars().map(
-                |c| match c {
-                    '0' => 0,
-                    '1' => 1,
-                    _ => 2,
+    pub fn parse_as_binary(&self, source: impl Into<Source>) -> Result<Vec<u32>, ParseError> {
+        self.parse_as_string(source)?.into_iter().enumerate().map(
+            |(i, line)| line.chars().enumerate().map(
+                |(j, c)| match c {
+                    '0' => Ok(0),
+                    '1' => Ok(1),
+                    _ => Err(ParseError::unexpected_token(i + 1, j + 1, "0 or 1", &c.to_string())),
                 }
-            ).collect::<Vec<_>>()
-        ).map(|bytes| if bytes.iter().all(|b| *b == 0 || *b == 1) { Ok(bytes) } else { Err(format!("Invalid byte in {:?}", bytes))}).collect::<Vec<_>>()
+            ).collect::<Result<Vec<_>, _>>()
+        ).collect::<Vec<_>>()
             .into_iter().map(|maybe_bytes| {
                 maybe_bytes.map(
                     |bytes| {
@@ -42,34 +84,154 @@ impl InputParser {
             }).collect()
     }
 
-    pub fn parse_as_string(&self, filepath: &str) -> Result<Vec<String>, String> {
-        let contents = self.parse_to_single_string(filepath)?;
-        Ok(contents.lines().map(|line| line.to_string()).collect())
+    pub fn parse_as_string(&self, source: impl Into<Source>) -> Result<Vec<String>, ParseError> {
+        let contents = self.parse_to_single_string(source)?;
+        Ok(Self::split_lines(&contents))
+    }
+
+    pub fn parse_as_string_chunks(&self, source: impl Into<Source>) -> Result<Vec<Vec<String>>, ParseError> {
+        let contents = self.parse_to_single_string(source)?;
+        Ok(Self::split_chunks(&contents))
+    }
+
+    pub fn parse_lines<T: FromStr>(&self, source: impl Into<Source>) -> Result<Vec<T>, ParseError> where T::Err: Display {
+        Self::lines_as(self.parse_as_string(source)?)
+    }
+
+    pub fn parse_chunks<T: FromStr>(&self, source: impl Into<Source>) -> Result<Vec<Vec<T>>, ParseError> where T::Err: Display {
+        Self::chunks_as(self.parse_as_string(source)?)
     }
 
-    pub fn parse_as_string_chunks(&self, filepath: &str, delimiter: &str) -> Result<Vec<Vec<String>>, String> {
-        let contents = self.parse_to_single_string(filepath)?;
-        Ok(contents.split(delimiter).map(|x| x.split("\n").map(|s| s.to_string()).collect::<Vec<_>>()).collect())
+    pub fn parse_grid<T: FromStr>(&self, source: impl Into<Source>) -> Result<Vec<Vec<T>>, ParseError> where T::Err: Display {
+        Self::grid_as(self.parse_as_string(source)?)
     }
     
-    pub fn parse_to_single_string(&self, filepath: &str) -> Result<String, String> {
-        let full_path_string = format!("{}{}", INPUT_PATH_HEAD, filepath);
-        let full_path = Path::new(&full_path_string);
-        let mut file = File::open(full_path).map_err(|_| "Unable to open file.".to_string())?;
+    // Line endings are normalized to \n, whatever the source.
+    pub fn parse_to_single_string(&self, source: impl Into<Source>) -> Result<String, ParseError> {
+        let contents = match source.into() {
+            Source::InputFile(filename) => Self::read_file(&self.get_input_root().join(filename)),
+            Source::Profile { year, day, profile } => self.read_profile(year, day, &profile),
+            Source::Path(path) => Self::read_file(&path),
+            Source::Stdin => {
+                let mut contents = String::new();
+                io::stdin().read_to_string(&mut contents).map_err(|e| ParseError::io(PathBuf::from("<stdin>"), e))?;
+                Ok(contents)
+            },
+            Source::Text(contents) => Ok(contents),
+        }?;
+        Ok(Self::normalize_line_endings(&contents))
+    }
+
+    // Each year has its own folder under the input root. Real inputs come from the store, which the fetch command can
+    // fill, while other profiles are only ever written by hand.
+    fn read_profile(&self, year: usize, day: usize, profile: &Profile) -> Result<String, ParseError> {
+        match profile {
+            Profile::Real => self.get_store().get(year, day),
+            profile => Self::read_file(&self.get_profile_path(year, day, profile)),
+        }
+    }
+
+    pub fn get_profile_path(&self, year: usize, day: usize, profile: &Profile) -> PathBuf {
+        match profile {
+            Profile::Real => self.get_store().get_path(year, day),
+            profile => self.get_input_root().join(year.to_string()).join(profile.get_input_filename(day)),
+        }
+    }
+
+    // The file that a source is read from, if it's read from a file at all.
+    pub fn get_path(&self, source: &Source) -> Option<PathBuf> {
+        match source {
+            Source::InputFile(filename) => Some(self.get_input_root().join(filename)),
+            Source::Profile { year, day, profile } => Some(self.get_profile_path(*year, *day, profile)),
+            Source::Path(path) => Some(path.clone()),
+            Source::Stdin | Source::Text(_) => None,
+        }
+    }
+
+    pub fn read_file(full_path: &Path) -> Result<String, ParseError> {
+        let mut file = File::open(full_path).map_err(|e| ParseError::io(full_path.to_path_buf(), e))?;
         let mut contents = String::new();
-        file.read_to_string(&mut contents).map_err(|_| "Unable to read file.".to_string())?;
-        return Ok(contents);
+        file.read_to_string(&mut contents).map_err(|e| ParseError::io(full_path.to_path_buf(), e))?;
+        Ok(contents)
+    }
+
+    pub fn normalize_line_endings(contents: &str) -> String {
+        contents.replace("\r\n", "\n")
     }
 
-    pub fn chunk<T>(input: Vec<T>, chunk_size: usize) -> Result<Vec<Vec<T>>, String> {
-        if input.len() % chunk_size != 0 {
-            return Err(format!("Input length {} is not divisible by chunk size {}.", input.len(), chunk_size));
+    pub fn split_lines(contents: &str) -> Vec<String> {
+        contents.lines().map(|line| line.to_string()).collect()
+    }
+
+    // Chunks are separated by one or more blank lines, and blank lines at either end are dropped.
+    pub fn split_chunks(contents: &str) -> Vec<Vec<String>> {
+        Self::number_chunks(Self::split_lines(contents)).into_iter()
+            .map(|chunk| chunk.into_iter().map(|(_, line)| line).collect())
+            .collect()
+    }
+
+    // Pairs each line in a chunk with its 1-indexed line number in the whole input.
+    pub fn number_chunks(lines: Vec<String>) -> Vec<Vec<(usize, String)>> {
+        let mut result = vec![];
+        let mut chunk = vec![];
+        for (i, line) in lines.into_iter().enumerate() {
+            if line.trim().is_empty() {
+                if !chunk.is_empty() {
+                    result.push(chunk);
+                    chunk = vec![];
+                }
+            } else {
+                chunk.push((i + 1, line));
+            }
+        }
+        if !chunk.is_empty() {
+            result.push(chunk);
+        }
+        result
+    }
+
+    // Parses each line on its own, so errors from `parse_line` should report line 1.
+    pub fn parse_each_line<T, F: Fn(String) -> Result<T, ParseError>>(lines: Vec<String>, parse_line: F) -> Result<Vec<T>, ParseError> {
+        lines.into_iter().enumerate().map(|(i, line)| parse_line(line).map_err(|e| e.offset_lines(i))).collect()
+    }
+
+    pub fn lines_as<T: FromStr>(lines: Vec<String>) -> Result<Vec<T>, ParseError> where T::Err: Display {
+        lines.iter().enumerate().map(|(i, line)| Self::parse_value(line, i + 1, 1)).collect()
+    }
+
+    pub fn chunks_as<T: FromStr>(lines: Vec<String>) -> Result<Vec<Vec<T>>, ParseError> where T::Err: Display {
+        Self::number_chunks(lines).into_iter().map(
+            |chunk| chunk.into_iter().map(|(line_number, line)| Self::parse_value(&line, line_number, 1)).collect()
+        ).collect()
+    }
+
+    // Parses each character on its own and checks that every row is as wide as the first.
+    pub fn grid_as<T: FromStr>(lines: Vec<String>) -> Result<Vec<Vec<T>>, ParseError> where T::Err: Display {
+        let grid = lines.iter().enumerate().map(
+            |(i, line)| line.chars().enumerate().map(|(j, c)| Self::parse_value(&c.to_string(), i + 1, j + 1)).collect()
+        ).collect::<Result<Vec<Vec<T>>, ParseError>>()?;
+        if grid.is_empty() || grid[0].is_empty() {
+            return Err(ParseError::Malformed("The grid should have at least one cell.".to_string()));
+        }
+        if let Some(i) = grid.iter().position(|row| row.len() != grid[0].len()) {
+            return Err(ParseError::invalid(i + 1, 1, format!("Expected {} cells in the row but got {}.", grid[0].len(), grid[i].len())));
+        }
+        Ok(grid)
+    }
+
+    fn parse_value<T: FromStr>(s: &str, line: usize, column: usize) -> Result<T, ParseError> where T::Err: Display {
+        s.parse::<T>().map_err(|e| ParseError::invalid(line, column, format!("Unable to parse {:?}: {}", s, e)))
+    }
+
+    pub fn chunk<T>(input: Vec<T>, chunk_size: usize) -> Result<Vec<Vec<T>>, ParseError> {
+        if !input.len().is_multiple_of(chunk_size) {
+            return Err(ParseError::invalid(input.len(), 1, format!("Input length {} is not divisible by chunk size {}.", input.len(), chunk_size)));
         }
         let mut result = vec![];
         let mut chunk = vec![];
         for (i, item) in input.into_iter().enumerate() {
             chunk.push(item);
-            if (i + 1) % chunk_size == 0 {
+            if (i + 1).is_multiple_of(chunk_size) {
                 result.push(chunk);
                 chunk = vec![];
             }
@@ -82,6 +244,11 @@ impl InputParser {
 
 #[cfg(test)]
 mod test_input {
+    use std::{fs, sync::Arc};
+
+    use crate::input::profile::Profile;
+    use crate::input::store::DirectoryFetcher;
+
     use super::*;
 
     #[test]
@@ -105,4 +272,122 @@ mod test_input {
 
 
     }
+
+    #[test]
+    fn test_input_parses_typed_lines() {
+        let lines = vec!["12".to_string(), "-4".to_string()];
+        assert_eq!(InputParser::lines_as::<i32>(lines).unwrap(), vec![12, -4]);
+
+        let lines = vec!["12".to_string(), "".to_string(), "4x".to_string()];
+        let error = InputParser::lines_as::<i32>(lines).unwrap_err();
+        assert_eq!(error.to_string(), "Line 2, column 1: Unable to parse \"\": cannot parse integer from empty string");
+    }
+
+    #[test]
+    fn test_input_parses_chunks() {
+        let lines = vec!["1", "2", "", "3", "", "", "4", "5", ""].into_iter().map(|s| s.to_string()).collect();
+        assert_eq!(InputParser::chunks_as::<u32>(lines).unwrap(), vec![vec![1, 2], vec![3], vec![4, 5]]);
+
+        let lines = vec!["1", "", "3", "x"].into_iter().map(|s| s.to_string()).collect();
+        assert_eq!(InputParser::chunks_as::<u32>(lines).unwrap_err().to_string(), "Line 4, column 1: Unable to parse \"x\": invalid digit found in string");
+    }
+
+    #[test]
+    fn test_input_splits_chunks_with_any_line_endings() {
+        let expected = vec![vec!["1".to_string(), "2".to_string()], vec!["3".to_string()], vec!["4".to_string(), "5".to_string()]];
+        let parser = InputParser::new();
+
+        assert_eq!(parser.parse_as_string_chunks("input_chunks_lf_test.txt").unwrap(), expected);
+        assert_eq!(parser.parse_as_string_chunks("input_chunks_crlf_test.txt").unwrap(), expected);
+        assert_eq!(parser.parse_chunks::<u32>("input_chunks_crlf_test.txt").unwrap(), vec![vec![1, 2], vec![3], vec![4, 5]]);
+        assert_eq!(parser.parse_as_string("input_chunks_crlf_test.txt").unwrap(), parser.parse_as_string("input_chunks_lf_test.txt").unwrap());
+        assert!(!parser.parse_to_single_string("input_chunks_crlf_test.txt").unwrap().contains('\r'));
+    }
+
+    #[test]
+    fn test_input_parses_grid() {
+        let lines = vec!["123".to_string(), "456".to_string()];
+        assert_eq!(InputParser::grid_as::<u8>(lines).unwrap(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        let lines = vec!["123".to_string(), "4a6".to_string()];
+        assert!(InputParser::grid_as::<u8>(lines).unwrap_err().to_string().starts_with("Line 2, column 2: Unable to parse \"a\""));
+
+        let lines = vec!["123".to_string(), "45".to_string()];
+        assert_eq!(InputParser::grid_as::<char>(lines).unwrap_err().to_string(), "Line 2, column 1: Expected 3 cells in the row but got 2.");
+    }
+
+    #[test]
+    fn test_input_root_resolves_in_order() {
+        let builder_root = Path::new("/some/builder/root");
+
+        assert_eq!(
+            InputParser::resolve_input_root(Some("/from/env".to_string()), Some(builder_root)),
+            PathBuf::from("/from/env")
+        );
+        assert_eq!(InputParser::resolve_input_root(None, Some(builder_root)), builder_root.to_path_buf());
+        assert_eq!(InputParser::resolve_input_root(Some("".to_string()), Some(builder_root)), builder_root.to_path_buf());
+        assert_eq!(
+            InputParser::resolve_input_root(None, None),
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("input")
+        );
+    }
+
+    #[test]
+    fn test_input_reads_from_builder_root() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("input");
+        let parsed = InputParser::new().with_input_root(root).parse_to_single_string("2022/input_06.txt").unwrap();
+
+        assert!(parsed.starts_with("dfsfmfbbbjnb"));
+    }
+
+    #[test]
+    fn test_input_reads_from_every_source() {
+        let parser = InputParser::new();
+        assert_eq!(parser.parse_lines::<u32>(Source::text("1\n2\n3")).unwrap(), vec![1, 2, 3]);
+
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("input").join("2022").join("input_06.txt");
+        assert_eq!(parser.parse_to_single_string(path.as_path()).unwrap(), parser.parse_to_single_string("2022/input_06.txt").unwrap());
+
+        let real = Source::Profile { year: 2022, day: 6, profile: Profile::Real };
+        assert_eq!(parser.parse_to_single_string(real).unwrap(), parser.parse_to_single_string("2022/input_06.txt").unwrap());
+
+        let example = Source::Profile { year: 2022, day: 7, profile: Profile::Example };
+        assert_eq!(parser.parse_to_single_string(example).unwrap(), parser.parse_to_single_string("2022/example_input_07.txt").unwrap());
+    }
+
+    #[test]
+    fn test_input_reads_real_inputs_from_the_store() {
+        let root = env::temp_dir().join(format!("input_store_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("remote/2022")).unwrap();
+        fs::write(root.join("remote/2022/input_18.txt"), "1,1,1\r\n").unwrap();
+        let fetcher = Arc::new(DirectoryFetcher::new(root.join("remote")));
+        let parser = InputParser::new().with_input_root(&root).with_store(InputStore::new(&root).with_fetcher(fetcher));
+
+        assert_eq!(parser.parse_to_single_string(Source::Profile { year: 2022, day: 18, profile: Profile::Real }).unwrap(), "1,1,1\n");
+        assert!(root.join("2022/input_18.txt").exists());
+        let error = parser.parse_to_single_string(Source::Profile { year: 2022, day: 18, profile: Profile::Example }).unwrap_err().to_string();
+        assert!(error.starts_with("Unable to read"), "{}", error);
+
+        // Reading an input never downloads it, even with a session set, unless the store was given a fetcher.
+        assert!(!InputParser::new().with_input_root(&root).get_store().has_fetcher());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_input_finds_source_paths() {
+        let parser = InputParser::new().with_input_root("/some/root");
+        assert_eq!(parser.get_path(&Source::Profile { year: 2022, day: 7, profile: Profile::Example }), Some(PathBuf::from("/some/root/2022/example_input_07.txt")));
+        assert_eq!(parser.get_path(&"input_i32_test.txt".into()), Some(PathBuf::from("/some/root/input_i32_test.txt")));
+        assert_eq!(parser.get_path(&Source::text("1")), None);
+    }
+
+    #[test]
+    fn test_input_names_missing_path() {
+        let error = InputParser::new().with_input_root("/no/such/root").parse_to_single_string("input_99.txt").unwrap_err().to_string();
+
+        assert!(error.contains("input_99.txt"), "{}", error);
+        assert!(error.contains("/no/such/root"), "{}", error);
+    }
 }
\ No newline at end of file
@@ -1 +1,6 @@
 pub mod input;
+pub mod overrides;
+pub mod profile;
+pub mod profiles;
+pub mod source;
+pub mod store;
@@ -0,0 +1,110 @@
+use std::collections::BTreeMap;
+
+use crate::utility::error::ParseError;
+use crate::utility::parser::Parser;
+
+use super::profiles::parse_key_value;
+
+// Parameter values that take precedence over each day's defaults and its profile's parameters. They're read from a
+// config file with one table per day, in the same format as the profiles file:
+//
+//     [day16]
+//     minutes_until_eruption = 40
+//
+// or given on the command line as name=value, in which case they win over the file and go to every day that's run
+// which has a parameter by that name.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParameterOverrides {
+    by_day: BTreeMap<usize, BTreeMap<String, String>>,
+    every_day: BTreeMap<String, String>,
+}
+
+impl ParameterOverrides {
+
+    pub fn from_string(s: &str) -> Result<Self, ParseError> {
+        let mut by_day: BTreeMap<usize, BTreeMap<String, String>> = BTreeMap::new();
+        let mut table = None;
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with('[') {
+                let day = Parser::parse_all(line, |parser| {
+                    parser.tag("[day")?;
+                    let day = parser.integer::<usize>()?;
+                    parser.tag("]")?;
+                    Ok(day)
+                }).map_err(|e| e.offset_lines(i))?;
+                table = Some(day);
+                continue;
+            }
+            let day = table.ok_or(ParseError::invalid(i + 1, 1, "Expected a [dayNN] table before the first parameter.".to_string()))?;
+            let (name, value) = Parser::parse_all(line, parse_key_value).map_err(|e| e.offset_lines(i))?;
+            if by_day.entry(day).or_default().insert(name.clone(), value).is_some() {
+                return Err(ParseError::invalid(i + 1, 1, format!("{} is set twice.", name)));
+            }
+        }
+        Ok(Self { by_day, every_day: BTreeMap::new() })
+    }
+
+    pub fn parse_assignment(s: &str) -> Result<(String, String), String> {
+        match s.split_once('=') {
+            Some((name, value)) if !name.trim().is_empty() => Ok((name.trim().to_string(), value.trim().to_string())),
+            _ => Err(format!("Expected a parameter as name=value but got: {}", s)),
+        }
+    }
+
+    pub fn insert(&mut self, name: String, value: String) {
+        self.every_day.insert(name, value);
+    }
+
+    pub fn get_day_parameters(&self, day: usize) -> BTreeMap<String, String> {
+        self.by_day.get(&day).cloned().unwrap_or_default()
+    }
+
+    pub fn get_command_line_parameters(&self) -> &BTreeMap<String, String> {
+        &self.every_day
+    }
+}
+
+#[cfg(test)]
+mod test_overrides {
+    use super::*;
+
+    #[test]
+    fn test_overrides_parse() {
+        let mut overrides = ParameterOverrides::from_string("\
+[day16]
+minutes_until_eruption = 40
+minutes_spent_teaching_elephant = 2
+
+[day11]
+rounds_a = 30
+").unwrap();
+        overrides.insert("minutes_until_eruption".to_string(), "26".to_string());
+
+        let parameters = overrides.get_day_parameters(16);
+        assert_eq!(parameters.get("minutes_until_eruption").map(|s| s.as_str()), Some("40"));
+        assert_eq!(parameters.get("minutes_spent_teaching_elephant").map(|s| s.as_str()), Some("2"));
+        assert_eq!(overrides.get_day_parameters(11).len(), 1);
+        assert!(overrides.get_day_parameters(1).is_empty());
+        assert_eq!(overrides.get_command_line_parameters().get("minutes_until_eruption").map(|s| s.as_str()), Some("26"));
+    }
+
+    #[test]
+    fn test_overrides_report_bad_input() {
+        let error = ParameterOverrides::from_string("rounds_a = 30").unwrap_err();
+        assert_eq!(error.to_string(), "Line 1, column 1: Expected a [dayNN] table before the first parameter.");
+        assert!(ParameterOverrides::from_string("[day11.real]").is_err());
+        assert!(ParameterOverrides::from_string("[day11]\nrounds_a = 30\nrounds_a = 40").is_err());
+    }
+
+    #[test]
+    fn test_parses_assignments() {
+        assert_eq!(ParameterOverrides::parse_assignment("rounds_a=30"), Ok(("rounds_a".to_string(), "30".to_string())));
+        assert_eq!(ParameterOverrides::parse_assignment("rounds_a = 30"), Ok(("rounds_a".to_string(), "30".to_string())));
+        assert!(ParameterOverrides::parse_assignment("rounds_a").is_err());
+        assert!(ParameterOverrides::parse_assignment("=30").is_err());
+    }
+}
@@ -0,0 +1,63 @@
+use std::fmt;
+
+// A named set of inputs, one per day. Within a year's input folder, the real profile reads input_NN.txt, and any other
+// profile reads <name>_input_NN.txt, so the example profile picks up the example_input_NN.txt files.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Profile {
+    #[default]
+    Real,
+    Example,
+    Named(String),
+}
+
+impl Profile {
+
+    pub fn from_string(s: &str) -> Result<Self, String> {
+        match s {
+            "real" => Ok(Self::Real),
+            "example" => Ok(Self::Example),
+            name if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') => {
+                Ok(Self::Named(name.to_string()))
+            },
+            _ => Err(format!("Unable to parse profile from string: {:?}", s)),
+        }
+    }
+
+    pub fn get_input_filename(&self, day: usize) -> String {
+        match self {
+            Self::Real => format!("input_{:02}.txt", day),
+            profile => format!("{}_input_{:02}.txt", profile, day),
+        }
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Real => write!(f, "real"),
+            Self::Example => write!(f, "example"),
+            Self::Named(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_profile {
+    use super::*;
+
+    #[test]
+    fn test_profile_parses() {
+        assert_eq!(Profile::from_string("real"), Ok(Profile::Real));
+        assert_eq!(Profile::from_string("example"), Ok(Profile::Example));
+        assert_eq!(Profile::from_string("large-2"), Ok(Profile::Named("large-2".to_string())));
+        assert!(Profile::from_string("").is_err());
+        assert!(Profile::from_string("../real").is_err());
+    }
+
+    #[test]
+    fn test_profile_names_input_files() {
+        assert_eq!(Profile::Real.get_input_filename(7), "input_07.txt");
+        assert_eq!(Profile::Example.get_input_filename(7), "example_input_07.txt");
+        assert_eq!(Profile::Named("large".to_string()).get_input_filename(15), "large_input_15.txt");
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::BTreeMap;
+
+use crate::problems::solution::Part;
+use crate::utility::error::ParseError;
+use crate::utility::parser::Parser;
+
+use super::profile::Profile;
+
+pub const PROFILES_FILENAME: &str = "profiles.toml";
+
+// Each year keeps its profiles next to its inputs.
+pub fn get_profiles_filename(year: usize) -> String {
+    format!("{}/{}", year, PROFILES_FILENAME)
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProfileSettings {
+    answers: BTreeMap<Part, String>,
+    parameters: BTreeMap<String, String>,
+}
+
+impl ProfileSettings {
+
+    pub fn get_answer(&self, part: Part) -> Option<&str> {
+        self.answers.get(&part).map(|answer| answer.as_str())
+    }
+
+    pub fn get_parameters(&self) -> &BTreeMap<String, String> {
+        &self.parameters
+    }
+}
+
+// Known-correct answers and parameters for each day's profiles, read from a small subset of TOML:
+//
+//     [day15.example]
+//     a = 26
+//     b = "56000011"
+//     row_y = 10
+//
+// Each table is named after a day and a profile. The keys a and b hold the expected answers, and any other key
+// sets a parameter for that day. Values are integers or double-quoted strings, which may use \n for answers that
+// span several lines.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProfileBook {
+    profiles: BTreeMap<(usize, Profile), ProfileSettings>,
+}
+
+impl ProfileBook {
+
+    pub fn from_string(s: &str) -> Result<Self, ParseError> {
+        let mut profiles: BTreeMap<(usize, Profile), ProfileSettings> = BTreeMap::new();
+        let mut table = None;
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with('[') {
+                let key = Parser::parse_all(line, Self::parse_table_header).map_err(|e| e.offset_lines(i))?;
+                profiles.entry(key.clone()).or_default();
+                table = Some(key);
+                continue;
+            }
+            let key = table.clone()
+                .ok_or(ParseError::invalid(i + 1, 1, "Expected a [dayNN.profile] table before the first key.".to_string()))?;
+            let (name, value) = Parser::parse_all(line, parse_key_value).map_err(|e| e.offset_lines(i))?;
+            let settings = profiles.entry(key).or_default();
+            let is_repeated = match name.as_str() {
+                "a" => settings.answers.insert(Part::A, value).is_some(),
+                "b" => settings.answers.insert(Part::B, value).is_some(),
+                _ => settings.parameters.insert(name.clone(), value).is_some(),
+            };
+            if is_repeated {
+                return Err(ParseError::invalid(i + 1, 1, format!("{} is set twice.", name)));
+            }
+        }
+        Ok(Self { profiles })
+    }
+
+    fn parse_table_header(parser: &mut Parser) -> Result<(usize, Profile), ParseError> {
+        parser.tag("[day")?;
+        let day = parser.integer::<usize>()?;
+        parser.tag(".")?;
+        let name = parser.consume_until(&|c| c == ']');
+        let profile = Profile::from_string(&name).map_err(|_| parser.unexpected_token("a profile name", &name))?;
+        parser.tag("]")?;
+        Ok((day, profile))
+    }
+
+    pub fn get(&self, day: usize, profile: &Profile) -> Option<&ProfileSettings> {
+        self.profiles.get(&(day, profile.clone()))
+    }
+
+    pub fn get_answer(&self, day: usize, part: Part, profile: &Profile) -> Option<&str> {
+        self.get(day, profile).and_then(|settings| settings.get_answer(part))
+    }
+
+    pub fn get_parameters(&self, day: usize, profile: &Profile) -> BTreeMap<String, String> {
+        self.get(day, profile).map(|settings| settings.get_parameters().clone()).unwrap_or_default()
+    }
+
+    pub fn get_profiles(&self, day: usize) -> Vec<Profile> {
+        self.profiles.keys().filter(|(d, _)| *d == day).map(|(_, profile)| profile.clone()).collect()
+    }
+}
+
+// A single `name = value` line, shared with the parameter config file. The value is an integer or a double-quoted
+// string and is returned as written, without quotes or escapes.
+pub fn parse_key_value(parser: &mut Parser) -> Result<(String, String), ParseError> {
+    let name = parser.consume_until(&|c| !(c.is_ascii_alphanumeric() || c == '_'));
+    if name.is_empty() {
+        let found = parser.peek_char().map(|c| c.to_string()).unwrap_or("end of line".to_string());
+        return Err(parser.unexpected_token("a key", &found));
+    }
+    parser.consume_whitespace();
+    parser.tag("=")?;
+    parser.consume_whitespace();
+    let value = match parser.optional(|p| p.tag("\"")) {
+        Some(_) => parse_string(parser)?,
+        None => parser.integer::<i64>()?.to_string(),
+    };
+    parser.consume_whitespace();
+    Ok((name, value))
+}
+
+fn parse_string(parser: &mut Parser) -> Result<String, ParseError> {
+    let mut to_return = String::new();
+    loop {
+        match parser.consume_n(1).as_str() {
+            "\"" => return Ok(to_return),
+            "\\" => match parser.consume_n(1).as_str() {
+                "n" => to_return.push('\n'),
+                "\"" => to_return.push('"'),
+                "\\" => to_return.push('\\'),
+                escape => return Err(parser.unexpected_token("n, \" or \\ after \\", escape)),
+            },
+            "" => return Err(parser.unexpected_token("\"", "end of line")),
+            c => to_return.push_str(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_profiles {
+    use super::*;
+
+    #[test]
+    fn test_profiles_parse() {
+        let book = ProfileBook::from_string("\
+# Comments and blank lines are skipped.
+
+[day01.real]
+a = 67622
+b = \"201491\"
+
+[day10.example]
+b = \"##..\\n#\\\"\\\\\"
+
+[day15.example]
+a = 26
+row_y = 10
+").unwrap();
+
+        assert_eq!(book.get_answer(1, Part::A, &Profile::Real), Some("67622"));
+        assert_eq!(book.get_answer(1, Part::B, &Profile::Real), Some("201491"));
+        assert_eq!(book.get_answer(10, Part::B, &Profile::Example), Some("##..\n#\"\\"));
+        assert_eq!(book.get_answer(10, Part::A, &Profile::Example), None);
+        assert_eq!(book.get_parameters(15, &Profile::Example), vec![("row_y".to_string(), "10".to_string())].into_iter().collect());
+        assert!(book.get_parameters(15, &Profile::Real).is_empty());
+        assert_eq!(book.get_profiles(1), vec![Profile::Real]);
+        assert!(book.get_profiles(2).is_empty());
+    }
+
+    #[test]
+    fn test_profiles_report_bad_input() {
+        let error = ProfileBook::from_string("a = 1").unwrap_err();
+        assert_eq!(error.to_string(), "Line 1, column 1: Expected a [dayNN.profile] table before the first key.");
+
+        let error = ProfileBook::from_string("[day01.real]\n\n= 1").unwrap_err();
+        assert_eq!(error.to_string(), "Line 3, column 1: expected \"a key\", but got \"=\"");
+
+        let error = ProfileBook::from_string("[day01.real]\na = \"12").unwrap_err();
+        assert_eq!(error.to_string(), "Line 2, column 8: expected \"\\\"\", but got \"end of line\"");
+
+        let error = ProfileBook::from_string("[day01.real]\na = 1\na = 2").unwrap_err();
+        assert_eq!(error.to_string(), "Line 3, column 1: a is set twice.");
+
+        assert!(ProfileBook::from_string("[dayone.real]").is_err());
+        assert!(ProfileBook::from_string("[day01.real input]").is_err());
+    }
+
+    #[test]
+    fn test_profiles_file_parses() {
+        let contents = crate::input::input::InputParser::new().parse_to_single_string(get_profiles_filename(2022).as_str()).unwrap();
+        let book = ProfileBook::from_string(&contents).unwrap();
+        assert_eq!(book.get_answer(1, Part::A, &Profile::Real), Some("67622"));
+        assert_eq!(book.get_parameters(15, &Profile::Example).get("row_y").map(|s| s.as_str()), Some("10"));
+    }
+}
@@ -0,0 +1,46 @@
+use std::path::{Path, PathBuf};
+
+use super::profile::Profile;
+
+// Where puzzle input comes from. Bare file names and profiles are looked up under the input root, while paths are
+// used as given.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    InputFile(String),
+    Profile { year: usize, day: usize, profile: Profile },
+    Path(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl Source {
+
+    pub fn text(s: &str) -> Self {
+        Self::Text(s.to_string())
+    }
+
+    pub fn from_argument(s: &str) -> Self {
+        match s {
+            "-" => Self::Stdin,
+            path => Self::Path(PathBuf::from(path)),
+        }
+    }
+}
+
+impl From<&str> for Source {
+    fn from(filename: &str) -> Self {
+        Self::InputFile(filename.to_string())
+    }
+}
+
+impl From<&Path> for Source {
+    fn from(path: &Path) -> Self {
+        Self::Path(path.to_path_buf())
+    }
+}
+
+impl From<PathBuf> for Source {
+    fn from(path: PathBuf) -> Self {
+        Self::Path(path)
+    }
+}
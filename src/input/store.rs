@@ -0,0 +1,247 @@
+use std::{env, fmt, fs, io::Write, path::{Path, PathBuf}, process::{Command, Stdio}, sync::Arc};
+
+use crate::utility::error::ParseError;
+
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// Anything that can produce a day's puzzle input when it isn't in the cache yet.
+pub trait Fetcher: fmt::Debug + Send + Sync {
+    fn fetch(&self, year: usize, day: usize) -> Result<String, String>;
+}
+
+// Downloads inputs from the website, which needs the session cookie of a logged-in browser. It shells out to curl
+// rather than pulling an HTTP client into the crate.
+#[derive(Clone)]
+pub struct SessionFetcher {
+    session: String,
+    base_url: String,
+}
+
+impl SessionFetcher {
+
+    pub fn new(session: String) -> Self {
+        Self { session, base_url: DEFAULT_BASE_URL.to_string() }
+    }
+
+    pub fn from_env() -> Option<Self> {
+        env::var(SESSION_ENV_VAR).ok().filter(|session| !session.trim().is_empty()).map(|session| Self::new(session.trim().to_string()))
+    }
+
+    pub fn get_url(&self, year: usize, day: usize) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    // curl reads this from stdin, since anything on its command line is visible to every local user.
+    fn get_curl_config(&self) -> String {
+        let cookie = format!("session={}", self.session).replace('\\', "\\\\").replace('"', "\\\"");
+        format!("cookie = \"{}\"\n", cookie)
+    }
+}
+
+// Keeps the session out of logs.
+impl fmt::Debug for SessionFetcher {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SessionFetcher").field("session", &"<hidden>").field("base_url", &self.base_url).finish()
+    }
+}
+
+impl Fetcher for SessionFetcher {
+    fn fetch(&self, year: usize, day: usize) -> Result<String, String> {
+        let url = self.get_url(year, day);
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--config", "-"])
+            .arg(&url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("Unable to run curl: {}", e))?;
+        // Dropping stdin once the config is written lets curl see the end of it.
+        child.stdin.take().expect("stdin is piped")
+            .write_all(self.get_curl_config().as_bytes())
+            .map_err(|e| format!("Unable to pass the session to curl: {}", e))?;
+        let output = child.wait_with_output().map_err(|e| format!("Unable to run curl: {}", e))?;
+        if !output.status.success() {
+            return Err(format!("Unable to download {}: {}", url, String::from_utf8_lossy(&output.stderr).trim()));
+        }
+        String::from_utf8(output.stdout).map_err(|e| format!("{} isn't valid UTF-8: {}", url, e))
+    }
+}
+
+// Serves inputs from a directory laid out like the cache, as <root>/<year>/input_NN.txt. It stands in for the
+// website wherever the network isn't wanted, such as in tests.
+#[derive(Clone, Debug)]
+pub struct DirectoryFetcher {
+    root: PathBuf,
+}
+
+impl DirectoryFetcher {
+
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl Fetcher for DirectoryFetcher {
+    fn fetch(&self, year: usize, day: usize) -> Result<String, String> {
+        let path = get_relative_path(year, day, &self.root);
+        fs::read_to_string(&path).map_err(|e| format!("Unable to read {}: {}", path.display(), e))
+    }
+}
+
+fn get_relative_path(year: usize, day: usize, root: &Path) -> PathBuf {
+    root.join(year.to_string()).join(format!("input_{:02}.txt", day))
+}
+
+// A cache of puzzle inputs keyed by year and day. Inputs that aren't cached yet come from the fetcher, if there is
+// one, and are saved so that later reads never fetch again.
+#[derive(Clone, Debug)]
+pub struct InputStore {
+    cache_root: PathBuf,
+    fetcher: Option<Arc<dyn Fetcher>>,
+}
+
+impl InputStore {
+
+    pub fn new<P: Into<PathBuf>>(cache_root: P) -> Self {
+        Self { cache_root: cache_root.into(), fetcher: None }
+    }
+
+    pub fn with_fetcher(mut self, fetcher: Arc<dyn Fetcher>) -> Self {
+        self.fetcher = Some(fetcher);
+        self
+    }
+
+    // Downloads from the website if a session is set, and otherwise only reads what's already cached. Only the fetch
+    // command builds a store this way, so nothing else reaches the network.
+    pub fn from_env<P: Into<PathBuf>>(cache_root: P) -> Self {
+        let store = Self::new(cache_root);
+        match SessionFetcher::from_env() {
+            Some(fetcher) => store.with_fetcher(Arc::new(fetcher)),
+            None => store,
+        }
+    }
+
+    pub fn has_fetcher(&self) -> bool {
+        self.fetcher.is_some()
+    }
+
+    pub fn get_path(&self, year: usize, day: usize) -> PathBuf {
+        get_relative_path(year, day, &self.cache_root)
+    }
+
+    // Whether there's an input to read, as opposed to nothing or the empty placeholder that scaffolding leaves.
+    pub fn is_cached(&self, year: usize, day: usize) -> bool {
+        fs::metadata(self.get_path(year, day)).is_ok_and(|metadata| metadata.len() > 0)
+    }
+
+    pub fn get(&self, year: usize, day: usize) -> Result<String, ParseError> {
+        if self.is_cached(year, day) {
+            let path = self.get_path(year, day);
+            return fs::read_to_string(&path).map_err(|e| ParseError::io(path, e));
+        }
+        self.fetch(year, day)
+    }
+
+    // Downloads the input even if it's already cached, replacing the cached copy.
+    pub fn fetch(&self, year: usize, day: usize) -> Result<String, ParseError> {
+        let path = self.get_path(year, day);
+        let fetcher = self.fetcher.as_ref().ok_or_else(|| ParseError::Unavailable(format!(
+            "There is no input for day {} of {}. Save it as {}, or set {} to your session cookie and run `fetch {} --year {}`.",
+            day, year, path.display(), SESSION_ENV_VAR, day, year
+        )))?;
+        let contents = fetcher.fetch(year, day)
+            .map_err(|e| ParseError::Unavailable(format!("Unable to fetch the input for day {} of {}: {}", day, year, e)))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| ParseError::io(parent.to_path_buf(), e))?;
+        }
+        fs::write(&path, &contents).map_err(|e| ParseError::io(path, e))?;
+        Ok(contents)
+    }
+}
+
+#[cfg(test)]
+mod test_store {
+    use std::{process, sync::atomic::{AtomicUsize, Ordering}};
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct CountingFetcher {
+        inner: DirectoryFetcher,
+        n_fetches: AtomicUsize,
+    }
+
+    impl Fetcher for CountingFetcher {
+        fn fetch(&self, year: usize, day: usize) -> Result<String, String> {
+            self.n_fetches.fetch_add(1, Ordering::SeqCst);
+            self.inner.fetch(year, day)
+        }
+    }
+
+    fn make_scratch_directory(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("store_{}_{}", name, process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_fetches_once_then_reads_the_cache() {
+        let root = make_scratch_directory("cache");
+        fs::create_dir_all(root.join("remote/2022")).unwrap();
+        fs::write(root.join("remote/2022/input_01.txt"), "1\n2\n").unwrap();
+        let fetcher = Arc::new(CountingFetcher { inner: DirectoryFetcher::new(root.join("remote")), n_fetches: AtomicUsize::new(0) });
+        let store = InputStore::new(root.join("cache")).with_fetcher(fetcher.clone());
+
+        assert_eq!(store.get(2022, 1).unwrap(), "1\n2\n");
+        assert_eq!(fs::read_to_string(root.join("cache/2022/input_01.txt")).unwrap(), "1\n2\n");
+        assert_eq!(store.get(2022, 1).unwrap(), "1\n2\n");
+        assert_eq!(fetcher.n_fetches.load(Ordering::SeqCst), 1);
+
+        let error = store.get(2021, 1).unwrap_err().to_string();
+        assert!(error.starts_with("Unable to fetch the input for day 1 of 2021: Unable to read"), "{}", error);
+        assert!(!store.get_path(2021, 1).exists());
+
+        fs::write(root.join("cache/2022/input_02.txt"), "").unwrap();
+        fs::write(root.join("remote/2022/input_02.txt"), "3\n").unwrap();
+        assert!(store.is_cached(2022, 1));
+        assert!(!store.is_cached(2022, 2));
+        assert_eq!(store.get(2022, 2).unwrap(), "3\n");
+        assert!(store.is_cached(2022, 2));
+        assert_eq!(fetcher.n_fetches.load(Ordering::SeqCst), 3);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_names_the_missing_day() {
+        let root = make_scratch_directory("missing");
+        let error = InputStore::new(&root).get(2022, 18).unwrap_err().to_string();
+
+        assert!(error.starts_with("There is no input for day 18 of 2022."), "{}", error);
+        assert!(error.contains(&root.join("2022").join("input_18.txt").display().to_string()), "{}", error);
+
+        fs::create_dir_all(root.join("2022")).unwrap();
+        fs::write(root.join("2022/input_18.txt"), "").unwrap();
+        let error = InputStore::new(&root).get(2022, 18).unwrap_err().to_string();
+        assert!(error.starts_with("There is no input for day 18 of 2022."), "{}", error);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_builds_session_urls() {
+        let fetcher = SessionFetcher::new("secret".to_string());
+        assert_eq!(fetcher.get_url(2022, 7), "https://adventofcode.com/2022/day/7/input");
+        assert!(!format!("{:?}", fetcher).contains("secret"));
+    }
+
+    #[test]
+    fn test_quotes_the_session_for_curl() {
+        assert_eq!(SessionFetcher::new("secret".to_string()).get_curl_config(), "cookie = \"session=secret\"\n");
+        assert_eq!(SessionFetcher::new("a\"b\\c".to_string()).get_curl_config(), "cookie = \"session=a\\\"b\\\\c\"\n");
+    }
+}
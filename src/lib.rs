@@ -0,0 +1,10 @@
+// Solutions to Advent of Code, with the parsing and geometry helpers they share. Other crates can reuse the helpers
+// in utility and input, look solvers up by year and day through the registry, or reach into a day's module for its
+// models. The runner is the command line that the binary wraps.
+pub mod input;
+pub mod problems;
+pub mod runner;
+pub mod utility;
+
+pub use problems::registry::{get_default_year, get_registered_days, get_registered_years, get_solver};
+pub use problems::solution::{Answer, Part, Solution, Solver};
@@ -1,7 +1,14 @@
-mod input;
-mod problems;
-mod utility;
+use std::{env, process};
+
+use advent_of_code_2022::runner::cli;
 
 fn main() {
-    println!("Hello, world!");
+    let args = env::args().skip(1).collect();
+    match cli::run(args) {
+        Ok(output) => println!("{}", output),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
 }
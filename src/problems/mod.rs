@@ -1,25 +1,4 @@
-pub mod problem_01;
-pub mod problem_02;
-pub mod problem_03;
-pub mod problem_04;
-pub mod problem_05;
-pub mod problem_06;
-pub mod problem_07;
-pub mod problem_08;
-pub mod problem_09;
-pub mod problem_10;
-pub mod problem_11;
-pub mod problem_12;
-pub mod problem_13;
-pub mod problem_14;
-pub mod problem_15;
-pub mod problem_16;
-pub mod problem_17;
-pub mod problem_18;
-pub mod problem_19;
-pub mod problem_20;
-pub mod problem_21;
-pub mod problem_22;
-pub mod problem_23;
-pub mod problem_24;
-pub mod problem_25;
+pub mod year2022;
+
+pub mod registry;
+pub mod solution;
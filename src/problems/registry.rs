@@ -0,0 +1,43 @@
+use super::*;
+use super::solution::Solver;
+
+// Every year with a module, in order. Each one also needs an arm in get_solver.
+const YEARS: &[usize] = &[
+    2022,
+];
+
+pub fn get_solver(year: usize, day: usize) -> Option<Box<dyn Solver>> {
+    match year {
+        2022 => year2022::registry::get_solver(day),
+        _ => None,
+    }
+}
+
+pub fn get_registered_days(year: usize) -> Vec<usize> {
+    (1..=25).filter(|day| get_solver(year, *day).is_some()).collect()
+}
+
+pub fn get_registered_years() -> Vec<usize> {
+    YEARS.to_vec()
+}
+
+// The runner works on the latest event unless it's told otherwise.
+pub fn get_default_year() -> usize {
+    *YEARS.last().expect("At least one year is registered.")
+}
+
+#[cfg(test)]
+mod test_registry {
+    use super::*;
+
+    #[test]
+    fn test_registers_years() {
+        assert_eq!(get_registered_years(), vec![2022]);
+        assert!(YEARS.iter().all(|year| !get_registered_days(*year).is_empty()));
+        assert_eq!(get_default_year(), 2022);
+        assert_eq!(get_registered_days(2022), (1..=25).collect::<Vec<_>>());
+        assert!(get_registered_days(2021).is_empty());
+        assert!(get_solver(2022, 6).is_some());
+        assert!(get_solver(2021, 6).is_none());
+    }
+}
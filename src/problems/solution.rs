@@ -0,0 +1,256 @@
+use std::{any, fmt, str::FromStr, time::{Duration, Instant}};
+
+use crate::utility::error::ParseError;
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Part {
+
+    pub fn from_string(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "a" | "1" => Ok(Self::A),
+            "b" | "2" => Ok(Self::B),
+            _ => Err(format!("Unable to parse Part from string: {}", s))
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::A => write!(f, "a"),
+            Self::B => write!(f, "b"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+    Lines(Vec<String>),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Unsigned(n) => write!(f, "{}", n),
+            Self::Signed(n) => write!(f, "{}", n),
+            Self::Text(s) => write!(f, "{}", s),
+            Self::Lines(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Self::Unsigned(n as u64)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Self::Unsigned(n as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Self::Unsigned(n)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Self {
+        Self::Signed(n as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Text(s)
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(lines: Vec<String>) -> Self {
+        Self::Lines(lines)
+    }
+}
+
+// A puzzle constant that a day declares so it can be changed without recompiling, e.g. the number of rounds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Parameter {
+    name: &'static str,
+    kind: &'static str,
+    value: String,
+    description: &'static str,
+}
+
+impl Parameter {
+
+    pub fn new<T: fmt::Display>(name: &'static str, value: T, description: &'static str) -> Self {
+        Self { name, kind: any::type_name::<T>(), value: value.to_string(), description }
+    }
+
+    pub fn parse<T: FromStr>(name: &str, value: &str) -> Result<T, String> {
+        value.parse::<T>().map_err(|_| format!("Unable to parse {} as {}: {}", name, any::type_name::<T>(), value))
+    }
+
+    pub fn get_name(&self) -> &str {
+        self.name
+    }
+
+    pub fn get_kind(&self) -> &str {
+        self.kind
+    }
+
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn get_description(&self) -> &str {
+        self.description
+    }
+}
+
+pub trait Solution {
+    type Input: Clone;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError>;
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError>;
+
+    // Days with puzzle constants declare them here, along with their current values, and accept new values in
+    // set_parameter.
+    fn get_parameters(&self) -> Vec<Parameter> {
+        Vec::new()
+    }
+
+    fn set_parameter(&mut self, name: &str, _value: &str) -> Result<(), String> {
+        Err(format!("Unknown parameter: {}", name))
+    }
+}
+
+// Object-safe view of a Solution so that days with different input types can live side by side.
+pub trait Solver {
+    fn solve(&self, part: Part, input: &str) -> Result<Answer, ParseError>;
+
+    // Times parsing alone when part is None. Otherwise parses without timing it and times only the part.
+    fn time(&self, part: Option<Part>, input: &str) -> Result<Duration, ParseError>;
+
+    fn get_parameters(&self) -> Vec<Parameter>;
+
+    fn set_parameter(&mut self, name: &str, value: &str) -> Result<(), String>;
+}
+
+impl<S: Solution> Solver for S {
+    fn solve(&self, part: Part, input: &str) -> Result<Answer, ParseError> {
+        let parsed = self.parse(input)?;
+        match part {
+            Part::A => self.part_a(parsed),
+            Part::B => self.part_b(parsed),
+        }
+    }
+
+    fn time(&self, part: Option<Part>, input: &str) -> Result<Duration, ParseError> {
+        let start = Instant::now();
+        let parsed = self.parse(input)?;
+        let parse_time = start.elapsed();
+
+        let start = Instant::now();
+        match part {
+            None => return Ok(parse_time),
+            Some(Part::A) => self.part_a(parsed)?,
+            Some(Part::B) => self.part_b(parsed)?,
+        };
+        Ok(start.elapsed())
+    }
+
+    fn get_parameters(&self) -> Vec<Parameter> {
+        Solution::get_parameters(self)
+    }
+
+    fn set_parameter(&mut self, name: &str, value: &str) -> Result<(), String> {
+        Solution::set_parameter(self, name, value)
+    }
+}
+
+#[cfg(test)]
+mod test_solution {
+    use super::*;
+
+    struct Doubler;
+
+    impl Solution for Doubler {
+        type Input = Vec<u32>;
+
+        fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+            input.lines().enumerate().map(|(i, l)| l.parse::<u32>().map_err(|e| ParseError::invalid(i + 1, 1, e.to_string()))).collect()
+        }
+
+        fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+            Ok(input.into_iter().map(|n| 2 * n).sum::<u32>().into())
+        }
+
+        fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+            Ok(input.into_iter().map(|n| (2 * n).to_string()).collect::<Vec<_>>().into())
+        }
+    }
+
+    #[test]
+    fn test_answers_display() {
+        assert_eq!(Answer::from(12_usize).to_string(), "12");
+        assert_eq!(Answer::from(-3_i32).to_string(), "-3");
+        assert_eq!(Answer::from("CMZ".to_string()).to_string(), "CMZ");
+        assert_eq!(Answer::from(vec!["#.".to_string(), ".#".to_string()]).to_string(), "#.\n.#");
+    }
+
+    #[test]
+    fn test_solver_parses_then_solves() {
+        let solver: Box<dyn Solver> = Box::new(Doubler);
+        assert_eq!(solver.solve(Part::A, "1\n2\n3").unwrap(), Answer::Unsigned(12));
+        assert_eq!(solver.solve(Part::B, "1\n2").unwrap(), Answer::Lines(vec!["2".to_string(), "4".to_string()]));
+        assert!(solver.solve(Part::A, "1\nx").unwrap_err().to_string().starts_with("Line 2, column 1:"));
+    }
+
+    #[test]
+    fn test_solver_times_phases() {
+        let solver: Box<dyn Solver> = Box::new(Doubler);
+        assert!(solver.time(None, "1\n2\n3").is_ok());
+        assert!(solver.time(Some(Part::B), "1\n2\n3").is_ok());
+        assert!(solver.time(None, "1\nx").is_err());
+        assert!(solver.time(Some(Part::A), "1\nx").is_err());
+    }
+
+    #[test]
+    fn test_solver_rejects_unknown_parameters() {
+        let mut solver: Box<dyn Solver> = Box::new(Doubler);
+        assert!(solver.get_parameters().is_empty());
+        assert_eq!(solver.set_parameter("rounds", "20"), Err("Unknown parameter: rounds".to_string()));
+    }
+
+    #[test]
+    fn test_parameters_are_typed() {
+        let parameter = Parameter::new("rounds", 20_usize, "Rounds to play.");
+        assert_eq!((parameter.get_name(), parameter.get_kind(), parameter.get_value()), ("rounds", "usize", "20"));
+        assert_eq!(Parameter::parse::<usize>("rounds", "40"), Ok(40));
+        assert_eq!(Parameter::parse::<usize>("rounds", "-4"), Err("Unable to parse rounds as usize: -4".to_string()));
+    }
+
+    #[test]
+    fn test_parses_part() {
+        assert_eq!(Part::from_string("a"), Ok(Part::A));
+        assert_eq!(Part::from_string("B"), Ok(Part::B));
+        assert_eq!(Part::from_string("2"), Ok(Part::B));
+        assert!(Part::from_string("c").is_err());
+    }
+
+}
@@ -0,0 +1,106 @@
+use crate::input::input::InputParser;
+use crate::problems::solution::{Answer, Solution};
+use crate::utility::error::ParseError;
+
+pub fn solve_problem_01a(input: Vec<Vec<usize>>) -> Result<usize, ParseError> {
+    let calorie_sums = get_calorie_sums(input)?;
+    Ok(calorie_sums.into_iter().max().unwrap())
+}
+
+pub fn solve_problem_01b(input: Vec<Vec<usize>>) -> Result<usize, ParseError> {
+    let mut calorie_sums = get_calorie_sums(input)?;
+    calorie_sums.sort();
+    Ok(calorie_sums.into_iter().rev().take(3).sum())
+}
+
+fn get_calorie_sums(input: Vec<Vec<usize>>) -> Result<Vec<usize>, ParseError> {
+    if input.is_empty() {
+        return Err(ParseError::Malformed("There are no elves in the input.".to_string()));
+    }
+    Ok(input.into_iter().map(|calories| calories.into_iter().sum()).collect())
+}
+
+#[derive(Default)]
+pub struct Problem01;
+
+impl Solution for Problem01 {
+    type Input = Vec<Vec<usize>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        InputParser::chunks_as(InputParser::split_lines(input))
+    }
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_01a(input)?.into())
+    }
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_01b(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod test_problem_01 {
+
+    use super::*;
+
+    #[test]
+    fn test_problem_01a_passes() {
+        
+        let input = InputParser::new().parse_chunks::<usize>("2022/input_01.txt").unwrap();
+        let shorted_input = input.iter().take(2).map(|i| i.clone()).collect();
+
+        assert_eq!(shorted_input, 
+            vec![
+                vec![
+                    2832,
+                    2108,
+                    3082,
+                    4328,
+                    6843,
+                    5121,
+                    2869,
+                    1366,
+                    2358,
+                    1680,
+                    4980,
+                    1161,
+                ],
+                vec![
+                    8026,
+                    2154,
+                    4242,
+                    1023,
+                    2744,
+                    3162,
+                    4093,
+                    1150,
+                    5397,
+                    2738,
+                    5657,
+                ]
+            ]
+        );
+        assert_eq!(solve_problem_01a(shorted_input).unwrap(), 40386);
+
+        let answer = solve_problem_01a(input).unwrap();
+        assert_eq!(answer, 67622);
+
+        assert!(matches!(solve_problem_01a(Vec::new()), Err(ParseError::Malformed(_))));
+    }
+    
+    #[test]
+    fn test_problem_01b_passes() {
+        let input = InputParser::new().parse_chunks::<usize>("2022/input_01.txt").unwrap();
+
+        let shorted_input = input.iter().take(4).map(|i| i.clone()).collect();
+
+        assert_eq!(solve_problem_01b(shorted_input).unwrap(), 147861);
+
+        let answer = solve_problem_01b(input).unwrap();
+        assert_eq!(answer, 201491);
+
+        assert!(matches!(solve_problem_01b(Vec::new()), Err(ParseError::Malformed(_))));
+    }
+
+}
\ No newline at end of file
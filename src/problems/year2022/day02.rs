@@ -1,21 +1,57 @@
 use crate::input::input::InputParser;
+use crate::problems::solution::{Answer, Solution};
+use crate::utility::error::ParseError;
+
+pub fn solve_problem_02a(input: Vec<(String, String)>) -> Result<u32, ParseError> {
+    let rounds = input.into_iter().enumerate().map(|(i, (lhs, rhs))| Ok((
+        HandShape::from_string_problem_02a(lhs).map_err(|e| e.offset_lines(i))?,
+        HandShape::from_string_problem_02a(rhs).map_err(|e| e.offset_lines(i))?
+    ))).collect::<Result<Vec<_>, ParseError>>()?;
+    Ok(rounds.into_iter().map(|(lhs, rhs)| HandShape::score_round(&lhs, &rhs)).sum())
+}
 
-fn solve_problem_02a(input: Vec<(String, String)>) -> u32 {
-    input.into_iter().map(|(lhs, rhs)| HandShape::score_round(
-        &HandShape::from_string_problem_02a(lhs),
-        &HandShape::from_string_problem_02a(rhs)
-    )).sum()
+pub fn solve_problem_02b(input: Vec<(String, String)>) -> Result<u32, ParseError> {
+    let rounds = input.into_iter().enumerate().map(|(i, (lhs, rhs))| Ok((
+        Outcome::from_string(&lhs).map_err(|e| e.offset_lines(i))?,
+        HandShape::from_string(rhs).map_err(|e| e.offset_lines(i))?
+    ))).collect::<Result<Vec<_>, ParseError>>()?;
+    Ok(rounds.into_iter().map(|(outcome, opponent_shape)| HandShape::score_round(
+        &outcome.get_hand_shape_to_achieve(&opponent_shape),
+        &opponent_shape
+    )).sum())
 }
 
-fn solve_problem_02b(input: Vec<(String, String)>) -> u32 {
-    input.into_iter().map(|(lhs, rhs)| HandShape::score_round(
-        &Outcome::from_string(&lhs).get_hand_shape_to_achieve(&HandShape::from_string(rhs.clone())),
-        &HandShape::from_string(rhs)
-    )).sum()
+#[derive(Default)]
+pub struct Problem02;
+
+impl Solution for Problem02 {
+    type Input = Vec<(String, String)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_strategy_guide(InputParser::split_lines(input))
+    }
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_02a(input)?.into())
+    }
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_02b(input)?.into())
+    }
+}
+
+pub fn parse_strategy_guide(input: Vec<String>) -> Result<Vec<(String, String)>, ParseError> {
+    InputParser::parse_each_line(input, |line| {
+        let x = line.chars().collect::<Vec<_>>();
+        if x.len() != 3 || x[1] != ' ' {
+            return Err(ParseError::invalid(1, 1, format!("Expected two letters separated by a space but got {:?}", line)));
+        }
+        Ok((x[2].to_string(), x[0].to_string()))
+    })
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-enum HandShape {
+pub enum HandShape {
     Rock,
     Paper,
     Scissors
@@ -23,21 +59,21 @@ enum HandShape {
 
 impl HandShape {
 
-    pub fn from_string(s: String) -> Self {
+    pub fn from_string(s: String) -> Result<Self, ParseError> {
         match s.as_str() {
-            "A" => Self::Rock,
-            "B" => Self::Paper,
-            "C" => Self::Scissors,
-            _ => panic!("Invalid hand shape: {}", s)
+            "A" => Ok(Self::Rock),
+            "B" => Ok(Self::Paper),
+            "C" => Ok(Self::Scissors),
+            _ => Err(ParseError::unexpected_token(1, 1, "A, B or C", &s))
         }
     }
 
-    pub fn from_string_problem_02a(s: String) -> Self {
+    pub fn from_string_problem_02a(s: String) -> Result<Self, ParseError> {
         match s.as_str() {
-            "A" | "X" => Self::Rock,
-            "B" | "Y" => Self::Paper,
-            "C" | "Z" => Self::Scissors,
-            _ => panic!("Invalid hand shape: {}", s)
+            "A" | "X" => Ok(Self::Rock),
+            "B" | "Y" => Ok(Self::Paper),
+            "C" | "Z" => Ok(Self::Scissors),
+            _ => Err(ParseError::unexpected_token(1, 1, "A, B, C, X, Y or Z", &s))
         }
     }
 
@@ -70,7 +106,7 @@ impl HandShape {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-enum Outcome {
+pub enum Outcome {
     Win,
     Draw,
     Lose,
@@ -86,12 +122,12 @@ impl Outcome {
         }
     }
 
-    pub fn from_string(s: &str) -> Self {
+    pub fn from_string(s: &str) -> Result<Self, ParseError> {
         match s {
-            "X" => Self::Lose,
-            "Y" => Self::Draw,
-            "Z" => Self::Win,
-            _ => panic!("Invalid outcome! {}", s)
+            "X" => Ok(Self::Lose),
+            "Y" => Ok(Self::Draw),
+            "Z" => Ok(Self::Win),
+            _ => Err(ParseError::unexpected_token(1, 3, "X, Y or Z", s))
         }
     }
 
@@ -118,8 +154,7 @@ mod test_problem_02 {
     #[test]
     fn test_problem_02a_passes() {
         
-        let input: Vec<_> = InputParser::new().parse_as_string("input_02.txt").unwrap()
-            .into_iter().map(|x| x.chars().collect::<Vec<_>>()).map(|x| (x[2].to_string(), x[0].to_string())).collect();
+        let input = parse_strategy_guide(InputParser::new().parse_as_string("2022/input_02.txt").unwrap()).unwrap();
         
         assert_eq!(input.len(), 2500);
 
@@ -129,7 +164,7 @@ mod test_problem_02 {
             ("C".to_string(), "Z".to_string()),
         ];
 
-        assert_eq!(solve_problem_02a(example_input), 15);
+        assert_eq!(solve_problem_02a(example_input).unwrap(), 15);
         // A Y => Y A => Paper vs. Rock => 6 + 2 = 8
         // B Z => Z B => Scissors vs. Paper => 6 + 3 = 9
         // C Y => Y C => Paper vs. Scissors => 0 + 2 = 2
@@ -140,9 +175,9 @@ mod test_problem_02 {
 
         let shorted_input = input.iter().take(5).map(|i| i.clone()).collect();
 
-        assert_eq!(solve_problem_02a(shorted_input), 32);
+        assert_eq!(solve_problem_02a(shorted_input).unwrap(), 32);
 
-        let answer = solve_problem_02a(input);
+        let answer = solve_problem_02a(input).unwrap();
 
         assert_eq!(answer, 15632);
     }
@@ -150,8 +185,7 @@ mod test_problem_02 {
     #[test]
     fn test_problem_02b_passes() {
         
-        let input: Vec<_> = InputParser::new().parse_as_string("input_02.txt").unwrap()
-            .into_iter().map(|x| x.chars().collect::<Vec<_>>()).map(|x| (x[2].to_string(), x[0].to_string())).collect();
+        let input = parse_strategy_guide(InputParser::new().parse_as_string("2022/input_02.txt").unwrap()).unwrap();
         
         // A Y => Y A => Draw vs. Rock => 3 + 1 = 4
         // B Z => Z B => Win vs. Paper => 6 + 3 = 9
@@ -163,9 +197,9 @@ mod test_problem_02 {
 
         let shorted_input = input.iter().take(5).map(|i| i.clone()).collect();
 
-        assert_eq!(solve_problem_02b(shorted_input), 28);
+        assert_eq!(solve_problem_02b(shorted_input).unwrap(), 28);
 
-        let answer = solve_problem_02b(input);
+        let answer = solve_problem_02b(input).unwrap();
         assert_eq!(answer, 14416);
     }
 
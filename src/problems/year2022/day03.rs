@@ -1,26 +1,51 @@
 use std::collections::HashSet;
 
 use crate::input::input::InputParser;
+use crate::problems::solution::{Answer, Solution};
+use crate::utility::error::ParseError;
 
 const AMOUNT_TO_SUBTRACT_FROM_LOWERCASE: u32 = 96;
 const AMOUNT_TO_SUBTRACT_FROM_UPPERCASE: u32 = 38;
 
-fn solve_problem_03a(input: Vec<String>) -> u32 {
-    input.into_iter().map(|rucksack| get_priority(get_common_element(rucksack))).sum()
+pub fn solve_problem_03a(input: Vec<String>) -> Result<u32, ParseError> {
+    let priorities = InputParser::parse_each_line(input, |rucksack| get_priority(get_common_element(rucksack)))?;
+    Ok(priorities.into_iter().sum())
 }
 
-fn solve_problem_03b(input: Vec<String>) -> u32 {
-    let teams = InputParser::chunk(input, 3).expect("We should be able to chunk the input into thirds.");
-    teams.into_iter().map(|team| get_priority(get_common_element_from_vec(team))).sum()
+pub fn solve_problem_03b(input: Vec<String>) -> Result<u32, ParseError> {
+    let teams = InputParser::chunk(input, 3)?;
+    let priorities = teams.into_iter().enumerate().map(
+        |(i, team)| get_priority(get_common_element_from_vec(team)).map_err(|e| e.offset_lines(3 * i))
+    ).collect::<Result<Vec<_>, ParseError>>()?;
+    Ok(priorities.into_iter().sum())
 }
 
-fn get_priority(c: char) -> u32 {
-    if c.is_lowercase() {
-        c as u32 - AMOUNT_TO_SUBTRACT_FROM_LOWERCASE
-    } else if c.is_uppercase() {
-        c as u32 - AMOUNT_TO_SUBTRACT_FROM_UPPERCASE
+#[derive(Default)]
+pub struct Problem03;
+
+impl Solution for Problem03 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(InputParser::split_lines(input))
+    }
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_03a(input)?.into())
+    }
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_03b(input)?.into())
+    }
+}
+
+fn get_priority(c: char) -> Result<u32, ParseError> {
+    if c.is_ascii_lowercase() {
+        Ok(c as u32 - AMOUNT_TO_SUBTRACT_FROM_LOWERCASE)
+    } else if c.is_ascii_uppercase() {
+        Ok(c as u32 - AMOUNT_TO_SUBTRACT_FROM_UPPERCASE)
     } else {
-        panic!("Input was neither lower nor uppercase: {}", c);
+        Err(ParseError::unexpected_token(1, 1, "a letter", &c.to_string()))
     }
 }
 
@@ -56,7 +81,7 @@ mod test_problem_03 {
     #[test]
     fn test_problem_03a_passes() {
         
-        let input = InputParser::new().parse_as_string("input_03.txt").unwrap();
+        let input = InputParser::new().parse_as_string("2022/input_03.txt").unwrap();
         
         let example = vec![
             "vJrwpWtwJgWrhcsFMMfFFhFp".to_string(),
@@ -66,7 +91,7 @@ mod test_problem_03 {
             "ttgJtRGJQctTZtZT".to_string(),
             "CrZsJsPPZsGzwwsLwLmpwMDw".to_string(),
         ];
-        assert_eq!(solve_problem_03a(example), 157);
+        assert_eq!(solve_problem_03a(example).unwrap(), 157);
         
         let shortened_input = input.iter().take(3).map(|i| i.clone()).collect();
 
@@ -75,15 +100,15 @@ mod test_problem_03 {
         // nVsqGpbbtDtTNmrmfZ => t => 20
 
         // 39 + 34 + 20 = 93
-        assert_eq!(solve_problem_03a(shortened_input), 93);
+        assert_eq!(solve_problem_03a(shortened_input).unwrap(), 93);
 
-        let answer = solve_problem_03a(input);
+        let answer = solve_problem_03a(input).unwrap();
         assert_eq!(answer, 7691);
     }
     
     #[test]
     fn test_problem_03b_passes() {
-        let input = InputParser::new().parse_as_string("input_03.txt").unwrap();
+        let input = InputParser::new().parse_as_string("2022/input_03.txt").unwrap();
         
         let example = vec![
             "vJrwpWtwJgWrhcsFMMfFFhFp".to_string(),
@@ -94,9 +119,9 @@ mod test_problem_03 {
             "CrZsJsPPZsGzwwsLwLmpwMDw".to_string(),
         ];
         
-        assert_eq!(solve_problem_03b(example), 70);
+        assert_eq!(solve_problem_03b(example).unwrap(), 70);
 
-        let answer = solve_problem_03b(input);
+        let answer = solve_problem_03b(input).unwrap();
         assert_eq!(answer, 2508);
     }
 
@@ -137,14 +162,14 @@ mod test_problem_03 {
         assert_eq!('a' as u32 - AMOUNT_TO_SUBTRACT_FROM_LOWERCASE, 1);
         assert_eq!('Z' as u32 - AMOUNT_TO_SUBTRACT_FROM_UPPERCASE, 52);
 
-        assert_eq!(get_priority('a'), 1);
-        assert_eq!(get_priority('b'), 2);
-        assert_eq!(get_priority('q'), 17);
-        assert_eq!(get_priority('H'), 34);
-        assert_eq!(get_priority('t'), 20);
-        assert_eq!(get_priority('z'), 26);
-        assert_eq!(get_priority('A'), 27);
-        assert_eq!(get_priority('Z'), 52);
+        assert_eq!(get_priority('a').unwrap(), 1);
+        assert_eq!(get_priority('b').unwrap(), 2);
+        assert_eq!(get_priority('q').unwrap(), 17);
+        assert_eq!(get_priority('H').unwrap(), 34);
+        assert_eq!(get_priority('t').unwrap(), 20);
+        assert_eq!(get_priority('z').unwrap(), 26);
+        assert_eq!(get_priority('A').unwrap(), 27);
+        assert_eq!(get_priority('Z').unwrap(), 52);
     }
 
     #[test]
@@ -0,0 +1,138 @@
+use crate::input::input::InputParser;
+use crate::problems::solution::{Answer, Solution};
+use crate::utility::error::{ParseError, parse_number};
+
+type Range = (u32, u32);
+type Pair = (Range, Range);
+
+pub fn solve_problem_04a(input: Vec<String>) -> Result<u32, ParseError> {
+    let pairs = InputParser::parse_each_line(input, parse_cleaning_pair)?;
+    Ok(pairs.into_iter()
+        .filter(|p| has_completely_overlapping(*p))
+        .count() as u32)
+}
+
+pub fn solve_problem_04b(input: Vec<String>) -> Result<u32, ParseError> {
+    let pairs = InputParser::parse_each_line(input, parse_cleaning_pair)?;
+    Ok(pairs.into_iter()
+        .filter(|p| overlaps(*p))
+        .count() as u32)
+}
+
+#[derive(Default)]
+pub struct Problem04;
+
+impl Solution for Problem04 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(InputParser::split_lines(input))
+    }
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_04a(input)?.into())
+    }
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_04b(input)?.into())
+    }
+}
+
+fn parse_cleaning_pair(s: String) -> Result<Pair, ParseError> {
+    let (left, right) = s.split_once(",")
+        .ok_or(ParseError::invalid(1, 1, format!("Expected two ranges separated by a comma but got {:?}", s)))?;
+    return Ok((parse_range(left, 1)?, parse_range(right, left.len() + 2)?));
+}
+
+fn parse_range(s: &str, column: usize) -> Result<Range, ParseError> {
+    let (start, end) = s.split_once("-")
+        .ok_or(ParseError::invalid(1, column, format!("Expected a range like 2-4 but got {:?}", s)))?;
+    Ok((parse_number(start, column)?, parse_number(end, column + start.len() + 1)?))
+}
+
+fn overlaps(pair: Pair) -> bool {
+    !are_sequential(pair)
+}
+
+fn are_sequential(pair: Pair) -> bool {
+    are_sequential_in_order(pair) || are_sequential_in_inverse_order(pair)
+}
+
+fn are_sequential_in_order(pair: Pair) -> bool {
+    let (left, right) = pair;
+    left.1 < right.0
+}
+
+fn are_sequential_in_inverse_order(pair: Pair) -> bool {
+    are_sequential_in_order((pair.1, pair.0))
+}
+
+fn has_completely_overlapping(pair: Pair) -> bool {
+    is_left_subset_of_right(pair) || is_right_subset_of_left(pair)
+}
+
+fn is_left_subset_of_right(pair: Pair) -> bool {
+    let left = pair.0;
+    let right = pair.1;
+    (left.0 >= right.0) && (left.1 <= right.1)
+}
+
+fn is_right_subset_of_left(pair: Pair) -> bool {
+    is_left_subset_of_right((pair.1, pair.0))
+}
+
+#[cfg(test)]
+mod test_problem_04 {
+
+    use super::*;
+
+    #[test]
+    fn test_problem_04a_passes() {
+        
+        let input = InputParser::new().parse_as_string("2022/input_04.txt").unwrap();
+        
+        let example = vec![
+            "2-4,6-8".to_string(),
+            "2-3,4-5".to_string(),
+            "5-7,7-9".to_string(),
+            "2-8,3-7".to_string(),
+            "6-6,4-6".to_string(),
+            "2-6,4-8".to_string(),
+
+        ];
+
+        assert_eq!(solve_problem_04a(example).unwrap(), 2);
+
+        let shorted_input = input.iter().take(10).map(|i| i.clone()).collect();
+
+        assert_eq!(solve_problem_04a(shorted_input).unwrap(), 6);
+
+        let answer = solve_problem_04a(input).unwrap();
+        assert_eq!(answer, 542);
+    }
+    
+    #[test]
+    fn test_problem_04b_passes() {
+        let input = InputParser::new().parse_as_string("2022/input_04.txt").unwrap();
+        let shorted_input = input.iter().take(10).map(|i| i.clone()).collect();
+        
+        let example = vec![
+            "2-4,6-8".to_string(),
+            "2-3,4-5".to_string(),
+            "5-7,7-9".to_string(),
+            "2-8,3-7".to_string(),
+            "6-6,4-6".to_string(),
+            "2-6,4-8".to_string(),
+
+        ];
+
+        assert_eq!(solve_problem_04b(example).unwrap(), 4);
+
+
+        assert_eq!(solve_problem_04b(shorted_input).unwrap(), 8);
+
+        let answer = solve_problem_04b(input).unwrap();
+        assert_eq!(answer, 900);
+    }
+
+}
\ No newline at end of file
@@ -1,24 +1,47 @@
 use std::collections::VecDeque;
 
 use crate::input::input::InputParser;
+use crate::utility::error::{ParseError, parse_number};
+use crate::problems::solution::{Answer, Solution};
 
-pub fn solve_problem_05a(input: String) -> String {
-    let (mut stacks, instructions) = parse_input(input);
+pub fn solve_problem_05a(input: String) -> Result<String, ParseError> {
+    let (mut stacks, instructions) = parse_input(input)?;
     
     stacks.handle_instructions(instructions, Model::Model9000);
-    return stacks.get_top_boxes_string();
+    return Ok(stacks.get_top_boxes_string());
 }
 
-fn solve_problem_05b(input: String) -> String {
-    let (mut stacks, instructions) = parse_input(input);
+pub fn solve_problem_05b(input: String) -> Result<String, ParseError> {
+    let (mut stacks, instructions) = parse_input(input)?;
     
     stacks.handle_instructions(instructions, Model::Model9001);
-    return stacks.get_top_boxes_string();
+    return Ok(stacks.get_top_boxes_string());
 }
 
-fn parse_input(input: String) -> (Stacks, Vec<Instruction>) {
+#[derive(Default)]
+pub struct Problem05;
+
+impl Solution for Problem05 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_05a(input)?.into())
+    }
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_05b(input)?.into())
+    }
+}
+
+fn parse_input(input: String) -> Result<(Stacks, Vec<Instruction>), ParseError> {
     let chunks = input.split("\n\n").collect::<Vec<_>>();
-    assert_eq!(chunks.len(), 2);
+    if chunks.len() != 2 {
+        return Err(ParseError::invalid(1, 1, format!("Expected stacks and instructions separated by a blank line but got {} sections.", chunks.len())));
+    }
     
     let (stacks_string, instructions_string) = (chunks[0], chunks[1]);
     
@@ -26,17 +49,17 @@ fn parse_input(input: String) -> (Stacks, Vec<Instruction>) {
     let l = stacks_string.len();
     let stacks_string: Vec<&str> = stacks_string.into_iter().take(l - 1).collect();
     let stacks_string = stacks_string.join("\n");
-    let stacks = Stacks::from_string(stacks_string).expect("Unable to parse Stacks.");
+    let stacks = Stacks::from_string(stacks_string)?;
 
-    let instructions = instructions_string.split("\n")
-        .map(|s| Instruction::from_string(s.to_string()).expect(&format!("Unable to parse instruction: {}", s)))
-        .collect();
+    let instructions_lines = instructions_string.split("\n").map(|s| s.to_string()).collect();
+    let instructions = InputParser::parse_each_line(instructions_lines, Instruction::from_string)
+        .map_err(|e| e.offset_lines(l + 1))?;
     
-    return (stacks, instructions);
+    return Ok((stacks, instructions));
 }
 
 #[derive(Debug)]
-struct Stacks {
+pub struct Stacks {
     stacks: Vec<Vec<char>>,
 }
 
@@ -46,8 +69,10 @@ impl Stacks {
         Self {stacks}
     }
 
-    pub fn from_string(s: String) -> Result<Self, String> {
-        let row_boxes: Result<Vec<Vec<Option<char>>>, String> = s.split("\n").map(|row_string| Self::parse_row(row_string.to_string())).collect();
+    pub fn from_string(s: String) -> Result<Self, ParseError> {
+        let row_boxes: Result<Vec<Vec<Option<char>>>, ParseError> = s.split("\n").enumerate()
+            .map(|(i, row_string)| Self::parse_row(row_string.to_string()).map_err(|e| e.offset_lines(i)))
+            .collect();
         let row_boxes: Vec<_> = row_boxes?.into_iter().rev().collect();
         assert!(row_boxes.len() > 0);
         let n_columns = row_boxes[0].len();
@@ -65,10 +90,11 @@ impl Stacks {
         return Ok(Self::new(stacks));
     }
 
-    fn parse_row(s: String) -> Result<Vec<Option<char>>, String> {
+    fn parse_row(s: String) -> Result<Vec<Option<char>>, ParseError> {
         let mut to_return = Vec::new();
         let mut chars = s.chars().collect::<VecDeque<_>>();
         while chars.len() > 0 {
+            let column = s.chars().count() - chars.len() + 1;
             let maybe_left_bracket = chars.pop_front();
             let maybe_char = chars.pop_front();
             let maybe_right_bracket = chars.pop_front();
@@ -78,12 +104,12 @@ impl Stacks {
                 Some(' ') => to_return.push(None),
                 Some('[') => {
                     let c = match maybe_char {
-                        None => {return Err("String ended prematurely.".to_string())},
+                        None => {return Err(ParseError::unexpected_token(1, column + 1, "a box label", "end of line"))},
                         Some(c) => c
                     };
                     to_return.push(Some(c));
                 }
-                c => return Err(format!("Got an unexpected character: {:?}", c))
+                c => return Err(ParseError::unexpected_token(1, column, "'[' or ' '", &format!("{:?}", c)))
             }
         }
         return Ok(to_return);
@@ -138,7 +164,7 @@ impl Stacks {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-struct Instruction {
+pub struct Instruction {
     number_to_move: usize,
     from_stack: usize,
     to_stack: usize,
@@ -154,15 +180,20 @@ impl Instruction {
         }
     }
 
-    pub fn from_string(s: String) -> Result<Self, String> {
+    pub fn from_string(s: String) -> Result<Self, ParseError> {
         let words = s.split_whitespace().collect::<Vec<_>>();
-        assert_eq!(words[0], "move");
-        let number_to_move = words[1].parse::<usize>().map_err(|_| "Couldn't parse number to move.".to_string())?;
-        assert_eq!(words[2], "from");
-        let from_stack = words[3].parse::<usize>().map_err(|_| "Couldn't parse from".to_string()).map(|n| n - 1)?;
-        assert_eq!(words[4], "to");
-        let to_stack = words[5].parse::<usize>().map_err(|_| "Couldn't parse to.".to_string()).map(|n| n - 1)?;
-        Ok(Self::new(number_to_move, from_stack, to_stack))
+        match words.as_slice() {
+            ["move", number_to_move, "from", from_stack, "to", to_stack] => {
+                let number_to_move = parse_number::<usize>(number_to_move, 1)?;
+                let from_stack = parse_number::<usize>(from_stack, 1)?;
+                let to_stack = parse_number::<usize>(to_stack, 1)?;
+                if from_stack == 0 || to_stack == 0 {
+                    return Err(ParseError::invalid(1, 1, format!("Stacks are numbered from 1: {:?}", s)));
+                }
+                Ok(Self::new(number_to_move, from_stack - 1, to_stack - 1))
+            },
+            _ => Err(ParseError::invalid(1, 1, format!("Expected an instruction like \"move 1 from 2 to 1\" but got {:?}", s))),
+        }
     }
 
     pub fn unpack(&self) -> (usize, usize, usize) {
@@ -171,7 +202,7 @@ impl Instruction {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-enum Model {
+pub enum Model {
     Model9000,
     Model9001,
 }
@@ -184,7 +215,7 @@ mod test_problem_05 {
     #[test]
     fn test_problem_05a_passes() {
         
-        let input = InputParser::new().parse_to_single_string("input_05.txt").unwrap();
+        let input = InputParser::new().parse_to_single_string("2022/input_05.txt").unwrap();
         
         let example = 
             "    [D]    \n".to_string() + 
@@ -197,15 +228,15 @@ mod test_problem_05 {
             &"move 2 from 2 to 1\n".to_string() + 
             &"move 1 from 1 to 2".to_string(); 
         
-        assert_eq!(solve_problem_05a(example), "CMZ".to_string());
+        assert_eq!(solve_problem_05a(example).unwrap(), "CMZ".to_string());
 
-        let answer = solve_problem_05a(input);
+        let answer = solve_problem_05a(input).unwrap();
         assert_eq!(answer, "BZLVHBWQF".to_string());
     }
     
     #[test]
     fn test_problem_05b_passes() {
-        let input = InputParser::new().parse_to_single_string("input_05.txt").unwrap();
+        let input = InputParser::new().parse_to_single_string("2022/input_05.txt").unwrap();
         
         let example = 
             "    [D]    \n".to_string() + 
@@ -218,9 +249,9 @@ mod test_problem_05 {
             &"move 2 from 2 to 1\n".to_string() + 
             &"move 1 from 1 to 2".to_string(); 
         
-        assert_eq!(solve_problem_05b(example), "MCD".to_string());
+        assert_eq!(solve_problem_05b(example).unwrap(), "MCD".to_string());
 
-        let answer = solve_problem_05b(input);
+        let answer = solve_problem_05b(input).unwrap();
         assert_eq!(answer, "TDGJQTZSL".to_string());
     }
 
@@ -296,7 +327,7 @@ mod test_problem_05 {
     #[test]
     fn test_instruction_parses_from_string() {
         
-        assert_eq!(Instruction::from_string("move 3 from 1 to 3".to_string()), Ok(Instruction::new(3, 0, 2)));
+        assert_eq!(Instruction::from_string("move 3 from 1 to 3".to_string()).unwrap(), Instruction::new(3, 0, 2));
 
     }
 
@@ -1,30 +1,49 @@
 use std::collections::HashSet;
 
 use crate::input::input::InputParser;
+use crate::problems::solution::{Answer, Solution};
+use crate::utility::error::ParseError;
 
-fn solve_problem_06a(input: String) -> usize {
+pub fn solve_problem_06a(input: String) -> Result<usize, ParseError> {
+    find_marker(input, 4)
+}
+
+pub fn solve_problem_06b(input: String) -> Result<usize, ParseError> {
+    find_marker(input, 14)
+}
+
+fn find_marker(input: String, marker_length: usize) -> Result<usize, ParseError> {
     let mut subroutine = Subroutine::new();
     for (i, c) in input.chars().enumerate() {
         subroutine.consume(c);
-        if subroutine.last_n_were_different(4) {
-            return i + 1;
+        if subroutine.last_n_were_different(marker_length) {
+            return Ok(i + 1);
         }
     }
-    panic!("Got to the end of the string without finding a distinct group.");
+    Err(ParseError::invalid(1, input.chars().count() + 1, format!("Got to the end of the string without finding {} distinct characters.", marker_length)))
 }
 
-fn solve_problem_06b(input: String) -> usize {
-    let mut subroutine = Subroutine::new();
-    for (i, c) in input.chars().enumerate() {
-        subroutine.consume(c);
-        if subroutine.last_n_were_different(14) {
-            return i + 1;
-        }
+#[derive(Default)]
+pub struct Problem06;
+
+impl Solution for Problem06 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_06a(input)?.into())
+    }
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_06b(input)?.into())
     }
-    panic!("Got to the end of the string without finding a distinct group.");
 }
 
-struct Subroutine {
+#[derive(Default)]
+pub struct Subroutine {
     consumed: Vec<char>,
 }
 
@@ -42,6 +61,10 @@ impl Subroutine {
         self.consumed.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.consumed.is_empty()
+    }
+
     pub fn last_n_were_different(&self, n: usize) -> bool {
         if (n > self.len()) {
             return false;
@@ -61,43 +84,43 @@ mod test_problem_06 {
     fn test_problem_06a_passes() {
 
         let example = "mjqjpqmgbljsphdztnvjfqwrcgsmlb".to_string();
-        assert_eq!(solve_problem_06a(example), 7);
+        assert_eq!(solve_problem_06a(example).unwrap(), 7);
 
         let example = "bvwbjplbgvbhsrlpgdmjqwftvncz".to_string();
-        assert_eq!(solve_problem_06a(example), 5);
+        assert_eq!(solve_problem_06a(example).unwrap(), 5);
 
         let example = "nppdvjthqldpwncqszvftbrmjlhg".to_string();
-        assert_eq!(solve_problem_06a(example), 6);
+        assert_eq!(solve_problem_06a(example).unwrap(), 6);
 
         let example = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".to_string();
-        assert_eq!(solve_problem_06a(example), 10);
+        assert_eq!(solve_problem_06a(example).unwrap(), 10);
 
-        let input = InputParser::new().parse_to_single_string("input_06.txt").unwrap();
+        let input = InputParser::new().parse_to_single_string("2022/input_06.txt").unwrap();
 
-        let answer = solve_problem_06a(input);
+        let answer = solve_problem_06a(input).unwrap();
         assert_eq!(answer, 1647);
     }
     
     #[test]
     fn test_problem_06b_passes() {
         let example = "mjqjpqmgbljsphdztnvjfqwrcgsmlb".to_string();
-        assert_eq!(solve_problem_06b(example), 19);
+        assert_eq!(solve_problem_06b(example).unwrap(), 19);
 
         let example = "bvwbjplbgvbhsrlpgdmjqwftvncz".to_string();
-        assert_eq!(solve_problem_06b(example), 23);
+        assert_eq!(solve_problem_06b(example).unwrap(), 23);
 
         let example = "nppdvjthqldpwncqszvftbrmjlhg".to_string();
-        assert_eq!(solve_problem_06b(example), 23);
+        assert_eq!(solve_problem_06b(example).unwrap(), 23);
 
         let example = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".to_string();
-        assert_eq!(solve_problem_06b(example), 29);
+        assert_eq!(solve_problem_06b(example).unwrap(), 29);
 
         let example = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".to_string();
-        assert_eq!(solve_problem_06b(example), 26);
+        assert_eq!(solve_problem_06b(example).unwrap(), 26);
 
-        let input = InputParser::new().parse_to_single_string("input_06.txt").unwrap();
+        let input = InputParser::new().parse_to_single_string("2022/input_06.txt").unwrap();
 
-        let answer = solve_problem_06b(input);
+        let answer = solve_problem_06b(input).unwrap();
         assert_eq!(answer, 2447);
     }
 
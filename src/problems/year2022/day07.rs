@@ -1,46 +1,71 @@
 use std::collections::{VecDeque, HashSet};
+use std::ops::{Deref, DerefMut};
 
 use crate::input::input::InputParser;
-
-pub fn solve_problem_07a(input: String) -> usize {
-    let mut stream = CommandStream::from_string(input).unwrap();
-    let file_tree = FileTreeNode::from_command_stream(stream);
-    return file_tree.redundant_sum_directory_sizes_if(|x| x <= 100000);
+use crate::utility::error::ParseError;
+use crate::utility::parser::Parser;
+use crate::problems::solution::{Answer, Solution};
+
+pub fn solve_problem_07a(input: String) -> Result<usize, ParseError> {
+    let mut stream = CommandStream::from_string(input)?;
+    let file_tree = FileTreeNode::from_command_stream(stream)?;
+    return Ok(file_tree.redundant_sum_directory_sizes_if(|x| x <= 100000));
 }
 
-fn solve_problem_07b(input: String) -> usize {
-    let mut stream = CommandStream::from_string(input).unwrap();
-    let file_tree = FileTreeNode::from_command_stream(stream);
+pub fn solve_problem_07b(input: String) -> Result<usize, ParseError> {
+    let mut stream = CommandStream::from_string(input)?;
+    let file_tree = FileTreeNode::from_command_stream(stream)?;
     let total_disk_space = 70_000_000;
     let needed_disk_space = 30_000_000;
     let total_size = file_tree.sum_sizes();
     let unused_disk_space = total_disk_space - total_size;
     let needed_disk_space = needed_disk_space - unused_disk_space;
-    return file_tree.smallest_directory_bigger_than(needed_disk_space);
+    return Ok(file_tree.smallest_directory_bigger_than(needed_disk_space));
 }
 
-enum FileTreeNode {
+#[derive(Default)]
+pub struct Problem07;
+
+impl Solution for Problem07 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_07a(input)?.into())
+    }
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_07b(input)?.into())
+    }
+}
+
+pub enum FileTreeNode {
     File(String, usize),
     Directory(String, Vec<FileTreeNode>),
 }
 
 impl FileTreeNode {
 
-    pub fn from_command_stream(mut stream: CommandStream) -> Self {
-        let next_command = stream.peek().expect("from_command_stream called on empty stream.");
-        assert_eq!(next_command, Command::Cd("/".to_string()));
-        let (to_return, _) = Self::from_command_stream_recursive(stream);
+    pub fn from_command_stream(mut stream: CommandStream) -> Result<Self, ParseError> {
+        let next_command = stream.peek();
+        if next_command != Some(Command::Cd("/".to_string())) {
+            return Err(ParseError::Malformed(format!("Expected the commands to start with cd / but got {:?}", next_command)));
+        }
+        let (to_return, _) = Self::from_command_stream_recursive(stream)?;
 
-        return to_return;
+        return Ok(to_return);
     }
 
-    fn from_command_stream_recursive(mut stream: CommandStream) -> (Self, CommandStream) {
+    fn from_command_stream_recursive(mut stream: CommandStream) -> Result<(Self, CommandStream), ParseError> {
 
-        let next_command = stream.pop().expect("from_command_stream called on empty stream.");
+        let next_command = stream.pop().ok_or(ParseError::Malformed("The commands ended while changing directory.".to_string()))?;
         match next_command {
             Command::Cd(outer_directory_name) => {
                 if outer_directory_name == ".." {
-                    panic!("Expected a proper CD but got ..");
+                    return Err(ParseError::Malformed("Expected a proper cd but got cd ..".to_string()));
                 }
                 let ls_command = stream.pop();
                 match ls_command {
@@ -62,17 +87,17 @@ impl FileTreeNode {
                             let _dot_dot = stream.pop();
                             break;
                         }
-                        let recursion_results = Self::from_command_stream_recursive(stream);
+                        let recursion_results = Self::from_command_stream_recursive(stream)?;
                         let directory_node = recursion_results.0;
                         stream = recursion_results.1;
                         directory_contents.push(directory_node);
                     }
-                    return (Self::Directory(outer_directory_name.to_string(), directory_contents), stream);
+                    return Ok((Self::Directory(outer_directory_name.to_string(), directory_contents), stream));
                     },
-                    x => panic!("Expected LS but got: {:?}", x)
+                    x => Err(ParseError::Malformed(format!("Expected ls after cd {} but got: {:?}", outer_directory_name, x)))
                 }
             }
-            _ => panic!("from_command_stream called on a non-initial, non-cd command: {:?}", next_command)
+            _ => Err(ParseError::Malformed(format!("Expected cd but got: {:?}", next_command)))
         }
     }
 
@@ -143,7 +168,7 @@ impl FileTreeNode {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-struct CommandStream {
+pub struct CommandStream {
     commands: VecDeque<Command>,
 }
 
@@ -153,7 +178,7 @@ impl CommandStream {
         Self {commands}
     }
 
-    pub fn from_string(commands: String) -> Result<Self, String> {
+    pub fn from_string(commands: String) -> Result<Self, ParseError> {
         let mut parser = CommandParser::new(commands);
         parser.parse()
     }
@@ -172,31 +197,31 @@ impl CommandStream {
 }
 
 #[derive(Debug)]
-struct CommandParser {
-    to_parse: String,
-    pointer: usize,
+pub struct CommandParser {
+    parser: Parser,
 }
 
-impl CommandParser {
+impl Deref for CommandParser {
+    type Target = Parser;
 
-    pub fn new(commands: String) -> Self {
-        Self { to_parse: commands, pointer: 0 }
+    fn deref(&self) -> &Parser {
+        &self.parser
     }
+}
 
-    pub fn n_remaining_to_parse(&self) -> usize {
-        self.to_parse.len() - self.pointer
+impl DerefMut for CommandParser {
+    fn deref_mut(&mut self) -> &mut Parser {
+        &mut self.parser
     }
+}
 
-    pub fn increment_pointer(&mut self, n: usize) {
-        let new_pointer_value = self.pointer + n;
-        if new_pointer_value > self.to_parse.len() {
-            self.pointer = self.to_parse.len();
-        } else {
-            self.pointer = new_pointer_value;
-        }
+impl CommandParser {
+
+    pub fn new(commands: String) -> Self {
+        Self { parser: Parser::new(commands) }
     }
 
-    pub fn parse(&mut self) -> Result<CommandStream, String> {
+    pub fn parse(&mut self) -> Result<CommandStream, ParseError> {
         let mut to_return = VecDeque::new();
         while self.n_remaining_to_parse() > 0 {
             to_return.push_back(self.parse_command()?);
@@ -204,59 +229,37 @@ impl CommandParser {
         return Ok(CommandStream::new(to_return));
     }
 
-    fn parse_command(&mut self) -> Result<Command, String> {
-        let _dollar_sign = self.expect("$ ")?;
-        match self.peek_char() {
-            Some('l') => {
-                let ls = self.expect("ls");
-                self.expect_end_of_line_or_file()?;
-                let contents: Result<Vec<CommandResult>, String> = self.consume_until_next_command().into_iter().map(|c| Self::parse_command_result(c)).collect();
-                return Ok(Command::Ls(contents?));
-            },
-            Some('c') => {
-                let cd = self.expect("cd ");
-                let directory = self.consume_until_whitespace();
-                self.expect_end_of_line_or_file()?;
-                return Ok(Command::Cd(directory));
-            },
-            c => return Err(format!("Unexpected character found: {:?}.\n\nParser: {:?}", c, self)),
+    fn parse_command(&mut self) -> Result<Command, ParseError> {
+        self.tag("$ ")?;
+        if self.one_of(&["ls", "cd "])? == "ls" {
+            self.expect_end_of_line_or_file()?;
+            let (first_line, _) = self.get_location();
+            let contents: Result<Vec<CommandResult>, ParseError> = self.consume_until_next_command().into_iter().enumerate()
+                .map(|(i, c)| Self::parse_command_result(c).map_err(|e| e.offset_lines(first_line - 1 + i)))
+                .collect();
+            return Ok(Command::Ls(contents?));
         }
+        let directory = Self::parse_name(self)?;
+        self.expect_end_of_line_or_file()?;
+        return Ok(Command::Cd(directory));
     }
 
-    fn parse_command_result(s: String) -> Result<CommandResult, String> {
-        let components = s.split_whitespace().collect::<Vec<_>>();
-        if components.len() != 2 {
-            return Err(format!("Expected two components of a command result but got {:?}", components));
-        }
-        match s.chars().nth(0) {
-            Some('d') => {
-                let (_dir_string, name_string) = (components[0], components[1]);
-                return Ok(CommandResult::Dir(name_string.to_string()));
-            },
-            Some(_) => {
-                let (size_string, name_string) = (components[0], components[1]);
-
-                return Ok(CommandResult::File(name_string.to_string(), size_string.parse::<usize>().map_err(|_| "Unable to parse size".to_string())?));
-            },
-            None => {
-                return Err("Tried to parse a string with no characters!".to_string());
+    fn parse_command_result(s: String) -> Result<CommandResult, ParseError> {
+        Parser::parse_all(&s, |parser| {
+            if parser.optional(|p| p.tag("dir ")).is_some() {
+                return Ok(CommandResult::Dir(Self::parse_name(parser)?));
             }
-        }
-    }
-    
-    fn peek_char(&self) -> Option<char> {
-        self.to_parse.chars().nth(self.pointer)
+            let size = parser.integer::<usize>()?;
+            parser.tag(" ")?;
+            Ok(CommandResult::File(Self::parse_name(parser)?, size))
+        })
     }
 
-    fn consume_line(&mut self) -> String {
-        for (i, c) in self.to_parse.chars().skip(self.pointer).enumerate() {
-            if c == '\n' {
-                let to_return = self.consume_n(i);
-                let _new_line = self.consume_n(1);
-                return to_return;
-            }
+    fn parse_name(parser: &mut Parser) -> Result<String, ParseError> {
+        match parser.consume_until_whitespace() {
+            name if name.is_empty() => Err(parser.unexpected_token("a name", "whitespace")),
+            name => Ok(name),
         }
-        self.consume_remaining()
     }
 
     fn consume_until_next_command(&mut self) -> Vec<String> {
@@ -270,57 +273,11 @@ impl CommandParser {
         }
         return to_return;
     }
-    
-    fn consume_until_whitespace(&mut self) -> String {
-        let whitespace_chars = vec![' ', '\t', '\n'].into_iter().collect::<HashSet<_>>();
-        for (i, c) in self.to_parse.chars().skip(self.pointer).enumerate() {
-            if whitespace_chars.contains(&c) {
-                let to_return = self.consume_n(i);
-                return to_return;
-            }
-        }
-        self.consume_remaining()
-    }
-
-    fn consume_remaining(&mut self) -> String {
-        self.consume_n(self.n_remaining_to_parse())
-    }
-
-    fn consume_n(&mut self, n: usize) -> String {
-        let to_return = if self.pointer + n > self.to_parse.len() {
-            self.to_parse[self.pointer..self.to_parse.len()].to_string()
-        } else {
-            self.to_parse[self.pointer..(self.pointer + n)].to_string()
-        };
-        self.increment_pointer(n);
-        return to_return;
-    }
-
-    fn expect_end_of_line_or_file(&mut self) -> Result<String, String> {
-        if self.pointer == self.to_parse.len() {
-            return Ok("".to_string());
-        }
-        self.expect_end_of_line()
-    }
-
-    fn expect_end_of_line(&mut self) -> Result<String, String> {
-        self.expect("\n")
-    }
-
-    fn expect(&mut self, s: &str) -> Result<String, String> {
-        let length = s.len();
-        let result = self.consume_n(length);
-        if result == s {
-            return Ok(result);
-        } else {
-            return Err(format!("Expected {}, but got {}", s, result));
-        }
-    }
 
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-enum Command {
+pub enum Command {
     Cd(String),
     Ls(Vec<CommandResult>),
 }
@@ -330,7 +287,7 @@ impl Command {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-enum CommandResult {
+pub enum CommandResult {
     Dir(String),
     File(String, usize),
 }
@@ -343,27 +300,27 @@ mod test_problem_07 {
     #[test]
     fn test_problem_07a_passes() {
         
-        let example = InputParser::new().parse_to_single_string("example_input_07.txt").unwrap();
+        let example = InputParser::new().parse_to_single_string("2022/example_input_07.txt").unwrap();
 
-        let answer = solve_problem_07a(example);
+        let answer = solve_problem_07a(example).unwrap();
         assert_eq!(answer, 95437);
 
-        let input = InputParser::new().parse_to_single_string("input_07.txt").unwrap();
+        let input = InputParser::new().parse_to_single_string("2022/input_07.txt").unwrap();
 
-        let answer = solve_problem_07a(input);
+        let answer = solve_problem_07a(input).unwrap();
         assert_eq!(answer, 1667443);
     }
     
     #[test]
     fn test_problem_07b_passes() {
-        let example = InputParser::new().parse_to_single_string("example_input_07.txt").unwrap();
+        let example = InputParser::new().parse_to_single_string("2022/example_input_07.txt").unwrap();
 
-        let answer = solve_problem_07b(example);
+        let answer = solve_problem_07b(example).unwrap();
         assert_eq!(answer, 24933642);
 
-        let input = InputParser::new().parse_to_single_string("input_07.txt").unwrap();
+        let input = InputParser::new().parse_to_single_string("2022/input_07.txt").unwrap();
 
-        let answer = solve_problem_07b(input);
+        let answer = solve_problem_07b(input).unwrap();
         assert_eq!(answer, 8998590); // 30324496 was too high
     }
 
@@ -396,7 +353,7 @@ mod test_problem_07 {
         ];
         let mut stream = CommandStream::from_string(input.join("\n")).unwrap();
         
-        let tree = FileTreeNode::from_command_stream(stream);
+        let tree = FileTreeNode::from_command_stream(stream).unwrap();
 
         assert_eq!(tree.len(), 6);
         assert_eq!(tree.depth(), 3);
@@ -430,7 +387,7 @@ mod test_problem_07 {
 
         let mut stream = CommandStream::from_string(input.join("\n")).unwrap();
         
-        let tree = FileTreeNode::from_command_stream(stream);
+        let tree = FileTreeNode::from_command_stream(stream).unwrap();
 
         assert_eq!(tree.len(), 14);
         assert_eq!(tree.depth(), 4);
@@ -468,7 +425,7 @@ mod test_problem_07 {
         
         let mut parser = CommandParser::new("awpoeiht".to_string());
         assert_eq!(parser.peek_char(), Some('a'));
-        assert_eq!(parser.pointer, 0);
+        assert_eq!(parser.n_remaining_to_parse(), 8);
 
         parser.consume_remaining();
         assert_eq!(parser.peek_char(), None);
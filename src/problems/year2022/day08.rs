@@ -1,59 +1,70 @@
 use std::collections::HashSet;
 
 use crate::input::input::InputParser;
+use crate::utility::error::ParseError;
 use crate::utility::direction::Direction;
+use crate::utility::grid::Grid;
+use crate::utility::vector::Vec2;
+use crate::problems::solution::{Answer, Solution};
 
-pub fn solve_problem_08a(input: Vec<String>) -> usize {
-    let forest = Forest::from_strings(input).unwrap();
-    forest.count_visible()
+pub fn solve_problem_08a(input: Vec<Vec<usize>>) -> Result<usize, ParseError> {
+    let forest = Forest::new(input)?;
+    Ok(forest.count_visible())
 }
 
-fn solve_problem_08b(input: Vec<String>) -> usize {
-    let forest = Forest::from_strings(input).unwrap();
-    forest.get_max_scenic_score()
+pub fn solve_problem_08b(input: Vec<Vec<usize>>) -> Result<usize, ParseError> {
+    let forest = Forest::new(input)?;
+    Ok(forest.get_max_scenic_score())
+}
+
+#[derive(Default)]
+pub struct Problem08;
+
+impl Solution for Problem08 {
+    type Input = Vec<Vec<usize>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        InputParser::grid_as(InputParser::split_lines(input))
+    }
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_08a(input)?.into())
+    }
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_08b(input)?.into())
+    }
 }
 
 #[derive(Debug)]
-struct Forest {
-    trees: Vec<Vec<usize>>
+pub struct Forest {
+    trees: Grid<usize>
 }
 
 impl Forest {
     
-    pub fn new(trees: Vec<Vec<usize>>) -> Self {
-        assert!(trees.len() > 0);
-        assert!(trees[0].len() > 0);
-        Self {trees}
-    }
-
-    pub fn from_strings(strings: Vec<String>) -> Result<Self, String> {
-        let trees: Result<Vec<Vec<usize>>, String> = strings.into_iter().map(
-            |s| s.chars().map(
-                |t| t.to_digit(10).map(|t| t as usize).ok_or("Could't parse tree as digit.".to_string())
-            ).collect()
-        ).collect();
-        trees.map(|t| Self::new(t))
+    pub fn new(trees: Vec<Vec<usize>>) -> Result<Self, ParseError> {
+        Ok(Self {trees: Grid::dense(trees)?})
     }
 
     pub fn n_rows(&self) -> usize {
-        self.trees.len()
+        self.trees.get_dimensions().1
     }
 
     pub fn n_columns(&self) -> usize {
-        assert!(self.trees.len() > 0);
-        self.trees[0].len()
+        self.trees.get_dimensions().0
     }
 
     pub fn get(&self, i: usize, j: usize) -> usize {
-        self.trees[i][j]
+        self.trees[Vec2::new(j as i32, i as i32)]
     }
 
     pub fn get_row(&self, i: usize) -> Vec<usize> {
-        self.trees[i].clone()
+        self.trees.get_row(i as i32).cloned().collect()
     }
 
     pub fn get_column(&self, j: usize) -> Vec<usize> {
-        self.trees.iter().map(|row| row[j]).collect()
+        self.trees.get_column(j as i32).cloned().collect()
     }
 
     pub fn get_max_scenic_score(&self) -> usize {
@@ -77,17 +88,8 @@ impl Forest {
 
     pub fn get_view_line(&self, direction: Direction, i: usize, j: usize) -> Vec<(usize, usize)> {
 
-        if direction == Direction::Left && j == 0 { return Vec::new() }
-        if direction == Direction::Right && j == self.n_columns() - 1 { return Vec::new() }
-        if direction == Direction::Down && i == self.n_rows() - 1 { return Vec::new() }
-        if direction == Direction::Up && i == 0 { return Vec::new() }
-
-        let trees_to_check: Vec<(usize, usize)> = match direction {
-            Direction::Left => (0..j).into_iter().map(|y| (i, y)).rev().collect(),
-            Direction::Right => ((j + 1)..(self.n_columns())).into_iter().map(|y| (i, y)).collect(),
-            Direction::Down => ((i + 1)..(self.n_rows())).into_iter().map(|x| (x, j)).collect(),
-            Direction::Up => (0..i).into_iter().map(|x| (x, j)).rev().collect(),
-        };
+        let trees_to_check = self.trees.get_line_of_sight(Vec2::new(j as i32, i as i32), direction.get_grid_delta())
+            .map(|v| (v.y() as usize, v.x() as usize));
 
         let mut to_return: Vec<(usize, usize)> = Vec::new();
         let height_to_match = self.get(i, j);
@@ -163,11 +165,11 @@ mod test_problem_08 {
             "33549".to_string(),
             "35390".to_string(),
         ];
-        assert_eq!(solve_problem_08a(example_input), 21);
+        assert_eq!(solve_problem_08a(InputParser::grid_as(example_input).unwrap()).unwrap(), 21);
 
-        let input = InputParser::new().parse_as_string("input_08.txt").unwrap();
+        let input = InputParser::new().parse_grid("2022/input_08.txt").unwrap();
 
-        let answer = solve_problem_08a(input);
+        let answer = solve_problem_08a(input).unwrap();
         assert_eq!(answer, 1676);
     }
     
@@ -181,11 +183,11 @@ mod test_problem_08 {
             "33549".to_string(),
             "35390".to_string(),
         ];
-        assert_eq!(solve_problem_08b(example_input), 8);
+        assert_eq!(solve_problem_08b(InputParser::grid_as(example_input).unwrap()).unwrap(), 8);
 
-        let input = InputParser::new().parse_as_string("input_08.txt").unwrap();
+        let input = InputParser::new().parse_grid("2022/input_08.txt").unwrap();
 
-        let answer = solve_problem_08b(input);
+        let answer = solve_problem_08b(input).unwrap();
         assert_eq!(answer, 313200);
     }
 
@@ -199,7 +201,7 @@ mod test_problem_08 {
             "35390".to_string(),
         ];
 
-        let forest = Forest::from_strings(example_input).unwrap();
+        let forest = Forest::new(InputParser::grid_as(example_input).unwrap()).unwrap();
         let expected = vec![
             (0, 0),
             (0, 1),
@@ -247,7 +249,7 @@ mod test_problem_08 {
             "35390".to_string(),
         ];
 
-        let forest = Forest::from_strings(example_input).unwrap();
+        let forest = Forest::new(InputParser::grid_as(example_input).unwrap()).unwrap();
         assert_eq!(
             forest.get_visible_in_row(1),
             vec![(1, 0), (1, 1), (1, 2), (1, 4)].into_iter().collect(),
@@ -273,7 +275,7 @@ mod test_problem_08 {
             "35390".to_string(),
         ];
 
-        let forest = Forest::from_strings(example_input).unwrap();
+        let forest = Forest::new(InputParser::grid_as(example_input).unwrap()).unwrap();
         assert_eq!(forest.get(0, 0), 3);
         assert_eq!(forest.get(4, 4), 0);
         assert_eq!(forest.get(3, 2), 5);
@@ -293,7 +295,7 @@ mod test_problem_08 {
             "35390".to_string(),
         ];
 
-        let forest = Forest::from_strings(example_input).unwrap();
+        let forest = Forest::new(InputParser::grid_as(example_input).unwrap()).unwrap();
         assert_eq!(forest.get_scenic_score(1, 2), 4);
         assert_eq!(forest.get_scenic_score(3, 2), 8);
     }
@@ -309,7 +311,7 @@ mod test_problem_08 {
             "35390".to_string(),
         ];
 
-        let forest = Forest::from_strings(example_input).unwrap();
+        let forest = Forest::new(InputParser::grid_as(example_input).unwrap()).unwrap();
         assert!((0..forest.n_rows()).into_iter().all(|i| forest.get_viewing_distance(Direction::Left, i, 0) == 0));
         assert!((0..forest.n_columns()).into_iter().all(|j| forest.get_viewing_distance(Direction::Down, 4, j) == 0));
         assert_eq!(forest.get_viewing_distance(Direction::Up, 1, 2), 1);
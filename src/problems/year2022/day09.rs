@@ -1,23 +1,43 @@
 use std::{collections::HashSet, iter};
 
-use crate::{input::input::InputParser, utility::{vector::Vec2, direction::Direction}};
+use crate::{input::input::InputParser, utility::{vector::Vec2, direction::Direction, error::ParseError}};
+use crate::problems::solution::{Answer, Solution};
 
-pub fn solve_problem_09a(input: Vec<String>) -> usize {
+pub fn solve_problem_09a(input: Vec<String>) -> Result<usize, ParseError> {
     let mut rope = RopeLink::new();
     
-    rope.make_moves_and_get_tails(input.into_iter().map(|s| Move::from_string(s).unwrap()).collect())
-        .into_iter().collect::<HashSet<_>>().len()
+    Ok(rope.make_moves_and_get_tails(InputParser::parse_each_line(input, Move::from_string)?)
+        .into_iter().collect::<HashSet<_>>().len())
 }
 
-fn solve_problem_09b(input: Vec<String>) -> usize {
+pub fn solve_problem_09b(input: Vec<String>) -> Result<usize, ParseError> {
     let mut rope = Rope::default(9);
 
-    rope.make_moves_and_get_tails(input.into_iter().map(|s| Move::from_string(s).unwrap()).collect())
-        .into_iter().collect::<HashSet<_>>().len()
+    Ok(rope.make_moves_and_get_tails(InputParser::parse_each_line(input, Move::from_string)?)
+        .into_iter().collect::<HashSet<_>>().len())
+}
+
+#[derive(Default)]
+pub struct Problem09;
+
+impl Solution for Problem09 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(InputParser::split_lines(input))
+    }
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_09a(input)?.into())
+    }
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_09b(input)?.into())
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-struct Rope {
+pub struct Rope {
     links: Vec<RopeLink>
 }
 
@@ -53,7 +73,7 @@ impl Rope {
 }
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
-struct RopeLink {
+pub struct RopeLink {
     head: Vec2,
     tail: Vec2,
 }
@@ -122,7 +142,7 @@ impl RopeLink {
 }
 
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
-struct Move {
+pub struct Move {
     direction: Direction,
     repetitions: usize,
 }
@@ -133,12 +153,14 @@ impl Move {
         Self {direction, repetitions}
     }
 
-    pub fn from_string(s: String) -> Result<Self, String> {
+    pub fn from_string(s: String) -> Result<Self, ParseError> {
         let parts = s.split_whitespace().collect::<Vec<_>>();
-        assert_eq!(parts.len(), 2);
+        if parts.len() != 2 {
+            return Err(ParseError::invalid(1, 1, format!("Expected a direction and a number of steps but got {:?}", s)));
+        }
         let (direction, repetitions) = (
             Direction::from_string(parts[0])?,
-            parts[1].parse::<usize>().map_err(|_| format!("Unable to parse repetitions: {:?}", parts[1]))?
+            parts[1].parse::<usize>().map_err(|e| ParseError::invalid(1, parts[0].len() + 2, format!("Unable to parse repetitions {:?}: {}", parts[1], e)))?
         );
         Ok(Self::new(direction, repetitions))
     }
@@ -184,22 +206,22 @@ mod test_problem_09 {
     fn test_problem_09a_passes() {
         
         let example = get_example_09a_input();
-        assert_eq!(solve_problem_09a(example), 13);
+        assert_eq!(solve_problem_09a(example).unwrap(), 13);
 
-        let input = InputParser::new().parse_as_string("input_09.txt").unwrap();
+        let input = InputParser::new().parse_as_string("2022/input_09.txt").unwrap();
 
-        let answer = solve_problem_09a(input);
+        let answer = solve_problem_09a(input).unwrap();
         assert_eq!(answer, 5878);
     }
 
     #[test]
     fn test_problem_09b_passes() {
         let example_input = get_example_09b_input();
-        assert_eq!(solve_problem_09b(example_input), 36);
+        assert_eq!(solve_problem_09b(example_input).unwrap(), 36);
 
-        let input = InputParser::new().parse_as_string("input_09.txt").unwrap();
+        let input = InputParser::new().parse_as_string("2022/input_09.txt").unwrap();
 
-        let answer = solve_problem_09b(input);
+        let answer = solve_problem_09b(input).unwrap();
         assert_eq!(answer, 2405);
     }
 
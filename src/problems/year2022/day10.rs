@@ -1,24 +1,85 @@
 use crate::input::input::InputParser;
+use crate::problems::solution::{Answer, Parameter, Solution};
+use crate::utility::error::{ParseError, parse_number};
 
-pub fn solve_problem_10a(input: Vec<String>) -> i32 {
-    let instructions = input.into_iter().map(|s| Instruction::from_string(&s)).collect();
+pub fn solve_problem_10a(input: Vec<String>, cycles: Vec<usize>) -> Result<i32, ParseError> {
+    let instructions = InputParser::parse_each_line(input, |s| Instruction::from_string(&s))?;
     let mut cpu = Cpu::new();
     cpu.consume_all(instructions);
-    cpu.get_signal_strength()
+    if let Some(cycle) = cycles.iter().find(|cycle| **cycle > cpu.get_cycles()) {
+        return Err(ParseError::Malformed(format!("The program finishes after {} cycles, before cycle {}.", cpu.get_cycles(), cycle)));
+    }
+    Ok(cpu.get_signal_strength(&cycles))
 }
 
-fn solve_problem_10b(input: Vec<String>) -> Vec<String> {
+pub fn solve_problem_10b(input: Vec<String>) -> Result<Vec<String>, ParseError> {
         let mut crt = Crt::new();
 
         let mut cpu = Cpu::new();
-        let instructions = input.into_iter().map(|s| Instruction::from_string(&s)).collect();
+        let instructions = InputParser::parse_each_line(input, |s| Instruction::from_string(&s))?;
         cpu.consume_all(instructions);
 
-        crt.render(cpu)
+        Ok(crt.render(cpu))
+}
+
+pub struct Problem10 {
+    pub first_cycle: usize,
+    pub cycle_interval: usize,
+    pub last_cycle: usize,
+}
+
+impl Problem10 {
+
+    pub fn get_signal_cycles(&self) -> Vec<usize> {
+        (self.first_cycle..=self.last_cycle).step_by(self.cycle_interval).collect()
+    }
+}
+
+impl Default for Problem10 {
+    fn default() -> Self {
+        Self { first_cycle: 20, cycle_interval: 40, last_cycle: 220 }
+    }
+}
+
+impl Solution for Problem10 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(InputParser::split_lines(input))
+    }
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_10a(input, self.get_signal_cycles())?.into())
+    }
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_10b(input)?.into())
+    }
+
+    fn get_parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::new("first_cycle", self.first_cycle, "First cycle whose signal strength counts towards part a."),
+            Parameter::new("cycle_interval", self.cycle_interval, "Cycles between the ones whose signal strength counts."),
+            Parameter::new("last_cycle", self.last_cycle, "Last cycle whose signal strength may count."),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "first_cycle" => self.first_cycle = Parameter::parse(name, value)?,
+            "cycle_interval" => match Parameter::parse(name, value)? {
+                0 => return Err("cycle_interval must be at least 1.".to_string()),
+                n => self.cycle_interval = n,
+            },
+            "last_cycle" => self.last_cycle = Parameter::parse(name, value)?,
+            _ => return Err(format!("Unknown parameter: {}", name)),
+        }
+        Ok(())
+    }
 }
 
 #[derive(Clone, Debug)]
-struct Crt {
+pub struct Crt {
     pixels: Vec<String>,
 }
 
@@ -71,8 +132,14 @@ impl Crt {
 }
     
 
+impl Default for Crt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[derive(Clone, Debug)]
-struct Cpu {
+pub struct Cpu {
     register: i32,
     register_history: Vec<i32>,
 }
@@ -98,14 +165,8 @@ impl Cpu {
         self.register_history[i]
     }
 
-    pub fn get_signal_strength(&self) -> i32 {
-        assert!(self.register_history.len() >= 220);
-        20*self.register_history[20] +
-            60*self.register_history[60] +
-            100*self.register_history[100] +
-            140*self.register_history[140] +
-            180*self.register_history[180] +
-            220*self.register_history[220]
+    pub fn get_signal_strength(&self, cycles: &[usize]) -> i32 {
+        cycles.iter().map(|cycle| *cycle as i32 * self.register_history[*cycle]).sum()
     }
 
     pub fn consume_all(&mut self, instructions: Vec<Instruction>) {
@@ -127,27 +188,27 @@ impl Cpu {
 
 }
 
+impl Default for Cpu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-enum Instruction {
+pub enum Instruction {
     NoOp,
     AddX(i32),
 }
 
 impl Instruction {
 
-    pub fn from_string(s: &str) -> Self {
+    pub fn from_string(s: &str) -> Result<Self, ParseError> {
         let words: Vec<_> = s.split_whitespace().collect();
-        assert!(words.len() > 0);
-        assert!(words.len() <= 2);
-
-        if words.len() == 1 {
-            assert_eq!(words[0], "noop");
-            return Self::NoOp;
+        match words.as_slice() {
+            ["noop"] => Ok(Self::NoOp),
+            ["addx", x] => Ok(Self::AddX(parse_number::<i32>(x, 6)?)),
+            _ => Err(ParseError::unexpected_token(1, 1, "noop or addx <n>", s)),
         }
-
-        assert_eq!(words[0], "addx");
-        let x = words[1].parse::<i32>().expect("X should be parsable as an i32");
-        return Self::AddX(x);
     }
 }
 
@@ -161,23 +222,24 @@ mod test_problem_10 {
     #[test]
     fn test_problem_10a_passes() {
         
-        let example_input = InputParser::new().parse_as_string("example_input_10.txt").unwrap();
-        assert_eq!(solve_problem_10a(example_input), 13140);
+        let example_input = InputParser::new().parse_as_string("2022/example_input_10.txt").unwrap();
+        assert_eq!(solve_problem_10a(example_input.clone(), Problem10::default().get_signal_cycles()).unwrap(), 13140);
+        assert!(solve_problem_10a(example_input, vec![20, 260]).is_err());
 
-        let input = InputParser::new().parse_as_string("input_10.txt").unwrap();
+        let input = InputParser::new().parse_as_string("2022/input_10.txt").unwrap();
 
-        let answer = solve_problem_10a(input);
+        let answer = solve_problem_10a(input, Problem10::default().get_signal_cycles()).unwrap();
         assert_eq!(answer, 14040);
     }
     
     #[test]
     fn test_problem_10b_passes() {
-        let example_input = InputParser::new().parse_as_string("example_input_10.txt").unwrap();
-        assert_eq!(solve_problem_10b(example_input), get_problem_10b_example_output());
+        let example_input = InputParser::new().parse_as_string("2022/example_input_10.txt").unwrap();
+        assert_eq!(solve_problem_10b(example_input).unwrap(), get_problem_10b_example_output());
 
-        let input = InputParser::new().parse_as_string("input_10.txt").unwrap();
+        let input = InputParser::new().parse_as_string("2022/input_10.txt").unwrap();
 
-        let answer = solve_problem_10b(input);
+        let answer = solve_problem_10b(input).unwrap();
         let expected = vec![
             "####..##...##....##.####...##.####.#....".to_string(),
             "...#.#..#.#..#....#....#....#.#....#....".to_string(),
@@ -206,12 +268,13 @@ mod test_problem_10 {
         let mut cpu = Cpu::new();
         let instructions = iter::repeat(Instruction::NoOp).take(240).collect();
         cpu.consume_all(instructions);
-        assert_eq!(cpu.get_signal_strength(), 20 + 60 + 100 + 140 + 180 + 220);
+        assert_eq!(cpu.get_signal_strength(&Problem10::default().get_signal_cycles()), 20 + 60 + 100 + 140 + 180 + 220);
 
         let mut cpu = Cpu::new();
         let instructions = iter::repeat(Instruction::AddX(1)).take(240).collect();
         cpu.consume_all(instructions);
-        assert_eq!(cpu.get_signal_strength(), 20*10 + 60*30 + 100*50 + 140*70 + 180*90 + 220*110);
+        assert_eq!(cpu.get_signal_strength(&Problem10::default().get_signal_cycles()), 20*10 + 60*30 + 100*50 + 140*70 + 180*90 + 220*110);
+        assert_eq!(cpu.get_signal_strength(&[20, 40]), 20*10 + 40*20);
         
         let mut cpu = Cpu::new();
         let instructions = vec![
@@ -233,8 +296,8 @@ mod test_problem_10 {
         );
 
         let mut cpu = Cpu::new();
-        let example_input = InputParser::new().parse_as_string("example_input_10.txt").unwrap();
-        let instructions = example_input.into_iter().map(|s| Instruction::from_string(&s)).collect();
+        let example_input = InputParser::new().parse_as_string("2022/example_input_10.txt").unwrap();
+        let instructions = example_input.into_iter().map(|s| Instruction::from_string(&s).unwrap()).collect();
         cpu.consume_all(instructions);
 
         assert_eq!(
@@ -294,8 +357,8 @@ mod test_problem_10 {
         let mut crt = Crt::new();
 
         let mut cpu = Cpu::new();
-        let example_input = InputParser::new().parse_as_string("example_input_10.txt").unwrap();
-        let instructions = example_input.into_iter().map(|s| Instruction::from_string(&s)).collect();
+        let example_input = InputParser::new().parse_as_string("2022/example_input_10.txt").unwrap();
+        let instructions = example_input.into_iter().map(|s| Instruction::from_string(&s).unwrap()).collect();
         cpu.consume_all(instructions);
 
         assert_eq![
@@ -0,0 +1,611 @@
+use crate::input::input::InputParser;
+use crate::utility::parser::Parser;
+use crate::problems::solution::{Answer, Parameter, Solution};
+use crate::utility::error::{ParseError, parse_number};
+
+type Destination = usize;
+
+const DEFAULT_RELIEF: u32 = 3;
+
+
+pub fn solve_problem_11a(input: String, n_rounds: usize, relief: u32) -> Result<usize, ParseError> {
+    if relief == 0 {
+        return Err(ParseError::Malformed("Relief must divide worry levels by at least 1.".to_string()));
+    }
+    let mut pool = MonkeyPool::from_string(input, false)?;
+    pool.set_relief(relief);
+    pool.execute_rounds(n_rounds, true)?;
+    let mut inspection_counts = pool.get_inspection_counts();
+    inspection_counts.sort();
+    let inspection_counts = inspection_counts.into_iter().rev().collect::<Vec<_>>();
+    Ok(inspection_counts[0] * inspection_counts[1])
+}
+
+pub fn solve_problem_11b(input: String, n_rounds: usize) -> Result<usize, ParseError> {
+    let mut pool = MonkeyPool::from_string(input, true)?;
+    pool.execute_rounds(n_rounds, false)?;
+    let mut inspection_counts = pool.get_inspection_counts();
+    inspection_counts.sort();
+    let inspection_counts = inspection_counts.into_iter().rev().collect::<Vec<_>>();
+    Ok(inspection_counts[0] * inspection_counts[1])
+}
+
+pub struct Problem11 {
+    pub rounds_a: usize,
+    pub rounds_b: usize,
+    pub relief: u32,
+}
+
+impl Default for Problem11 {
+    fn default() -> Self {
+        Self { rounds_a: 20, rounds_b: 10_000, relief: DEFAULT_RELIEF }
+    }
+}
+
+impl Solution for Problem11 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_11a(input, self.rounds_a, self.relief)?.into())
+    }
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_11b(input, self.rounds_b)?.into())
+    }
+
+    fn get_parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::new("rounds_a", self.rounds_a, "Rounds of monkey business in part a, when worry is relieved."),
+            Parameter::new("rounds_b", self.rounds_b, "Rounds of monkey business in part b, when worry isn't relieved."),
+            Parameter::new("relief", self.relief, "What worry levels are divided by after each inspection in part a."),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "rounds_a" => self.rounds_a = Parameter::parse(name, value)?,
+            "rounds_b" => self.rounds_b = Parameter::parse(name, value)?,
+            "relief" => match Parameter::parse(name, value)? {
+                0 => return Err("relief must be at least 1.".to_string()),
+                n => self.relief = n,
+            },
+            _ => return Err(format!("Unknown parameter: {}", name)),
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MonkeyPool {
+    monkeys: Vec<Monkey>,
+    relief: u32,
+}
+
+impl MonkeyPool {
+
+    pub fn new(monkeys: Vec<Monkey>) -> Self {
+        Self { monkeys, relief: DEFAULT_RELIEF }
+    }
+
+    pub fn set_relief(&mut self, relief: u32) {
+        assert!(relief > 0, "Relief can't divide by zero.");
+        self.relief = relief;
+    }
+
+    pub fn from_string(s: String, use_moduli: bool) -> Result<Self, ParseError> {
+        let mut parser = Parser::new(s);
+        let mut monkeys = Vec::new();
+        while let Ok(_monkey_header) = parser.expect("Monkey ") {
+            let _monkey_number = parser.consume_line();
+
+            let starting_items = Self::consume_starting_items(&mut parser)?;
+            let operation = Self::consume_operation(&mut parser)?;
+            let (test_divisor, true_destination, false_destination) = Self::consume_test(&mut parser)?;
+
+            let monkey = Monkey::new(starting_items, operation, test_divisor, true_destination, false_destination);
+            monkeys.push(monkey);
+            let _whitespace = parser.consume_whitespace();
+        }
+
+        parser.expect_end_of_line_or_file()?;
+        if monkeys.len() < 2 {
+            return Err(ParseError::Malformed(format!("Expected at least two monkeys but got {}.", monkeys.len())));
+        }
+        if let Some(destination) = monkeys.iter().flat_map(|m| [m.true_destination, m.false_destination]).find(|d| *d >= monkeys.len()) {
+            return Err(ParseError::Malformed(format!("Monkey {} doesn't exist.", destination)));
+        }
+
+        let pool = Self::new(monkeys);
+        if use_moduli {
+            Ok(pool.modulize())
+        } else {
+            Ok(pool)
+        }
+
+    }
+
+    pub fn modulize(self) -> Self {
+        let moduli = self.monkeys.iter().map(|m| m.get_test_divisor()).collect::<Vec<_>>();
+        Self::new(self.monkeys.into_iter().map(|m| m.modulize(&moduli)).collect())
+    }
+
+    pub fn n_monkeys(&self) -> usize {
+        self.monkeys.len()
+    }
+
+    pub fn get_monkeys(&self) -> &Vec<Monkey> {
+        &self.monkeys
+    }
+
+    fn consume_starting_items(parser: &mut Parser) -> Result<Vec<ItemWorryLevel>, ParseError> {
+            let _whitespace = parser.consume_whitespace();
+            let _starting_items_header = parser.expect("Starting items: ")?;
+            let (line, column) = parser.get_location();
+            let starting_items_string = parser.consume_line();
+            
+            let mut item_column = column;
+            let mut starting_items = Vec::new();
+            for s in starting_items_string.split(", ") {
+                let n = parse_number::<u64>(s, item_column).map_err(|e| e.offset_lines(line - 1))?;
+                starting_items.push(ItemWorryLevel::N(n));
+                item_column += s.len() + 2;
+            }
+            return Ok(starting_items);
+    }
+
+    fn consume_operation(parser: &mut Parser) -> Result<Operation, ParseError> {
+        let _whitespace = parser.consume_whitespace();
+        let _operation_header = parser.expect("Operation: new = old ")?;
+        let (line, column) = parser.get_location();
+        let operand = parser.consume_n(1);
+        let _whitespace = parser.consume_whitespace();
+        if parser.peek_char() == Some('o') {
+            parser.expect("old")?;
+            parser.expect_end_of_line()?;
+            return match operand.as_str() {
+                "*" => Ok(Operation::Square),
+                _ => Err(ParseError::unexpected_token(line, column, "*", &operand)),
+            };
+        }
+        let n = parser.consume_number::<u32>(&|c| c.is_whitespace())?;
+
+        let to_return = match operand.as_str() {
+            "+" => Operation::Add(n),
+            "*" => Operation::Multiply(n),
+            _ => return Err(ParseError::unexpected_token(line, column, "+ or *", &operand)),
+        };
+
+        parser.expect_end_of_line()?;
+
+        return Ok(to_return);
+    }
+
+    fn consume_test(parser: &mut Parser) -> Result<(u32, Destination, Destination), ParseError> {
+        let _whitespace = parser.consume_whitespace();
+        let _test_header = parser.expect("Test: divisible by ")?;
+        let (line, column) = parser.get_location();
+        let test_divisor = parser.consume_number::<u32>(&|c| c.is_whitespace())?;
+        if test_divisor == 0 {
+            return Err(ParseError::invalid(line, column, "Nothing is divisible by 0.".to_string()));
+        }
+        let _whitespace = parser.consume_whitespace();
+        let true_destination = Self::consume_destination(parser, true)?;
+        let _whitespace = parser.consume_whitespace();
+        let false_destination = Self::consume_destination(parser, false)?;
+
+        Ok((test_divisor, true_destination, false_destination))
+    }
+
+    fn consume_destination(parser: &mut Parser, condition: bool) -> Result<Destination, ParseError> {
+        let condition_text = if condition { "true" } else { "false" };
+        let _header = parser.expect(&format!("If {}: throw to monkey ", condition_text))?;
+        parser.consume_number::<usize>(&|c| c.is_whitespace())
+    }
+
+    pub fn execute_rounds(&mut self, n: usize, with_relief: bool) -> Result<(), ParseError> {
+        (0..n).try_for_each(|_| self.execute_round(with_relief))
+    }
+
+    pub fn execute_round(&mut self, with_relief: bool) -> Result<(), ParseError> {
+        let relief = if with_relief { Some(self.relief) } else { None };
+        for i in (0..self.n_monkeys()) {
+            for (item, destination) in self.monkeys[i].execute_round(relief)?.into_iter() {
+                self.monkeys[destination].push(item);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_inspection_counts(&self) -> Vec<usize> {
+        self.monkeys.iter().map(|m| m.get_inspection_count()).collect()
+    }
+
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Monkey {
+    items: Vec<ItemWorryLevel>,
+    operation: Operation,
+    test_divisor: u32,
+    true_destination: usize,
+    false_destination: usize,
+    inspection_count: usize,
+}
+
+impl Monkey {
+
+    pub fn new(
+        items: Vec<ItemWorryLevel>,
+        operation: Operation,
+        test_divisor: u32,
+        true_destination: usize,
+        false_destination: usize,
+    ) -> Self {
+        Self {
+            items,
+            operation,
+            test_divisor,
+            true_destination,
+            false_destination,
+            inspection_count: 0,
+        }
+    }
+
+    pub fn modulize(self, moduli: &Vec<u32>) -> Self {
+        Self::new(
+            self.items.into_iter().map(|i| i.modulize(moduli)).collect(),
+            self.operation,
+            self.test_divisor,
+            self.true_destination,
+            self.false_destination
+        )
+    }
+    pub fn get_items(&self) -> Vec<ItemWorryLevel> {
+        self.items.clone()
+    }
+
+    pub fn apply_operation(&self, n: ItemWorryLevel) -> Result<ItemWorryLevel, ParseError> {
+        self.operation.apply(n)
+    }
+
+    pub fn get_test_divisor(&self) -> u32 {
+        self.test_divisor
+    }
+
+    pub fn test(&self, n: &ItemWorryLevel) -> bool {
+        n.is_disible_by(self.test_divisor)
+    }
+
+    pub fn get_destination(&self, b: bool) -> Destination {
+        if b {
+            self.true_destination
+        } else {
+            self.false_destination
+        }
+    }
+
+    pub fn get_inspection_count(&self) -> usize {
+        self.inspection_count
+    }
+
+    pub fn execute_round(&mut self, relief: Option<u32>) -> Result<Vec<(ItemWorryLevel, Destination)>, ParseError> {
+        
+        let maybe_apply_relief = |n: ItemWorryLevel| match relief {
+                Some(relief) => match n {
+                    ItemWorryLevel::N(n) => ItemWorryLevel::N(Self::apply_relief(n, relief)),
+                    ItemWorryLevel::Moduli(_) => panic!("Can't apply relief using moduli.")
+                },
+                None => n,
+            };
+        let new_worry_levels = self.items.iter().map(
+            |n| self.apply_operation(n.clone()).map(maybe_apply_relief)
+        ).collect::<Result<Vec<_>, _>>()?;
+        let to_return: Vec<(ItemWorryLevel, Destination)> = new_worry_levels.into_iter().map(
+            |new_worry_level| (new_worry_level.clone(), self.get_destination(self.test(&new_worry_level)))
+        ).collect();
+
+        (0..to_return.len()).into_iter().for_each(|_| self.inspection_count += 1);
+
+        self.items.clear();
+
+        Ok(to_return)
+    }
+
+    pub fn apply_relief(n: u64, relief: u32) -> u64 {
+        n / relief as u64
+    }
+
+    pub fn push(&mut self, n: ItemWorryLevel) {
+        self.items.push(n);
+    }
+    
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum ItemWorryLevel {
+    N(u64),
+    Moduli(ItemWorryLevelModuli),
+}
+
+impl ItemWorryLevel {
+
+    // Only plain worry levels can overflow, since each level in a moduli stays below its modulus.
+    pub fn checked_add(self, other: u32) -> Option<Self> {
+        match self {
+            Self::N(n) => n.checked_add(other as u64).map(Self::N),
+            Self::Moduli(moduli) => Some(Self::Moduli(moduli.map_levels(|level| level + other as u64))),
+        }
+    }
+
+    pub fn checked_mul(self, other: u32) -> Option<Self> {
+        match self {
+            Self::N(n) => n.checked_mul(other as u64).map(Self::N),
+            Self::Moduli(moduli) => Some(Self::Moduli(moduli.map_levels(|level| level * other as u64))),
+        }
+    }
+
+    pub fn checked_square(self) -> Option<Self> {
+        match self {
+            Self::N(n) => n.checked_mul(n).map(Self::N),
+            Self::Moduli(moduli) => Some(Self::Moduli(moduli.map_levels(|level| level * level))),
+        }
+    }
+
+    pub fn modulize(self, moduli: &Vec<u32>) -> Self {
+        match self {
+            Self::N(n) => { Self::Moduli(ItemWorryLevelModuli::from_n(n, moduli.clone())) },
+            Self::Moduli(m) => { panic!("Already modulized!") },
+        }
+    }
+
+    pub fn unwrap(&self) -> u64 {
+        match self {
+            Self::N(n) => *n,
+            _ => panic!("Can't unwrap a moduli!")
+        }
+    }
+
+    pub fn from_n(n: u64, maybe_moduli: Option<Vec<u32>>) -> Self {
+        match maybe_moduli {
+            None => Self::N(n),
+            Some(moduli) => Self::Moduli(ItemWorryLevelModuli::from_n(n, moduli))
+        }
+    }
+
+    pub fn is_disible_by(&self, divisor: u32) -> bool {
+        match self {
+            Self::N(n) => {
+                n % divisor as u64 == 0
+            },
+            Self::Moduli(moduli) => {
+                match moduli.moduli.binary_search(&divisor) {
+                    Ok(i) => {
+                        moduli.levels[i] == 0
+                    },
+                    Err(e) => panic!("Divisor wasn't found in moduli: {}", divisor)
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ItemWorryLevelModuli {
+    levels: Vec<u32>,
+    moduli: Vec<u32>,
+}
+
+impl ItemWorryLevelModuli {
+
+    pub fn new(levels: Vec<u32>, moduli: Vec<u32>) -> Self {
+        assert_eq!(levels.len(), moduli.len());
+        let mut pairs = levels.into_iter().zip(moduli).collect::<Vec<(u32, u32)>>();
+        pairs.sort_by(|a, b| a.1.cmp(&b.1));
+        let levels = pairs.iter().map(|x| x.0).collect();
+        let moduli = pairs.iter().map(|x| x.1).collect();
+        Self { levels, moduli }
+    }
+
+    pub fn from_n(n: u64, moduli: Vec<u32>) -> Self {
+        let levels = moduli.iter().map(|m| (n % *m as u64) as u32).collect();
+        Self::new(levels, moduli)
+    }
+
+    // Works in u64 so that the product of a level and anything up to u32::MAX can't overflow before it's reduced.
+    fn map_levels(self, f: impl Fn(u64) -> u64) -> Self {
+        let levels = self.moduli.iter().zip(self.levels).map(|(modulus, level)| (f(level as u64) % *modulus as u64) as u32).collect();
+        Self::new(levels, self.moduli)
+    }
+
+}
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Operation {
+    Add(u32),
+    Multiply(u32),
+    Square,
+}
+
+impl Operation {
+
+    pub fn apply(&self, n: ItemWorryLevel) -> Result<ItemWorryLevel, ParseError> {
+        let result = match self {
+            Self::Add(m) => n.clone().checked_add(*m),
+            Self::Multiply(m) => n.clone().checked_mul(*m),
+            Self::Square => n.clone().checked_square(),
+        };
+        result.ok_or_else(|| ParseError::Malformed(format!(
+            "A worry level of {} overflows under {:?}; use fewer rounds or more relief.", n.unwrap(), self
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test_problem_11 {
+
+    use super::*;
+
+    fn get_example_monkey_config() -> String {
+        InputParser::new().parse_to_single_string("2022/example_input_11.txt").unwrap()
+    }
+
+    #[test]
+    fn test_problem_11a_passes() {
+        
+        assert_eq!(solve_problem_11a(get_example_monkey_config(), 20, DEFAULT_RELIEF).unwrap(), 10605);
+
+        let input = InputParser::new().parse_to_single_string("2022/input_11.txt").unwrap();
+
+        let answer = solve_problem_11a(input, 20, DEFAULT_RELIEF).unwrap();
+        assert_eq!(answer, 64032);
+    }
+    
+    #[test]
+    fn test_problem_11b_passes() {
+        assert_eq!(solve_problem_11b(get_example_monkey_config(), 10_000).unwrap(), 2713310158);
+
+        let input = InputParser::new().parse_to_single_string("2022/input_11.txt").unwrap();
+
+        let answer = solve_problem_11b(input, 10_000).unwrap();
+        assert_eq!(answer, 12729522272);
+    }
+
+    #[test]
+    fn test_monkey_pool_reports_bad_input() {
+        let config = get_example_monkey_config().replace("Operation: new = old * 19", "Operation: new = old / 19");
+        let error = MonkeyPool::from_string(config, false).unwrap_err();
+        assert_eq!(error.to_string(), "Line 3, column 24: expected \"+ or *\", but got \"/\"");
+
+        let config = get_example_monkey_config().replace("If true: throw to monkey 2", "If true: throw to monkey 7");
+        assert_eq!(MonkeyPool::from_string(config, false).unwrap_err().to_string(), "Monkey 7 doesn't exist.");
+
+        let config = get_example_monkey_config().replace("Test: divisible by 23", "Test: divisible by 0");
+        assert_eq!(MonkeyPool::from_string(config, true).unwrap_err().to_string(), "Line 4, column 22: Nothing is divisible by 0.");
+    }
+
+    #[test]
+    fn test_rejects_zero_relief() {
+        assert!(solve_problem_11a(get_example_monkey_config(), 20, 0).is_err());
+
+        let mut problem = Problem11::default();
+        assert_eq!(problem.set_parameter("relief", "0"), Err("relief must be at least 1.".to_string()));
+        assert_eq!(problem.relief, DEFAULT_RELIEF);
+    }
+
+    #[test]
+    fn test_reports_overflow_without_relief() {
+        match solve_problem_11a(get_example_monkey_config(), 20, 1) {
+            Ok(answer) => assert_eq!(answer, solve_problem_11b(get_example_monkey_config(), 20).unwrap()),
+            Err(error) => assert!(matches!(error, ParseError::Malformed(_)), "{}", error),
+        }
+    }
+
+    #[test]
+    fn test_parses_into_monkey_pool() {
+        let pool = MonkeyPool::from_string(get_example_monkey_config(), false).unwrap();
+        assert_eq!(pool.n_monkeys(), 4);
+
+        let monkeys = pool.get_monkeys();
+        assert_eq!(monkeys[0].get_items(), vec![ItemWorryLevel::N(79), ItemWorryLevel::N(98)]);
+        assert_eq!(monkeys[3].get_items(), vec![ItemWorryLevel::N(74)]);
+
+        assert_eq!(monkeys[1].apply_operation(ItemWorryLevel::N(5)).unwrap(), ItemWorryLevel::N(11));
+        assert_eq!(monkeys[2].test(&ItemWorryLevel::N(26)), true);
+        assert_eq!(monkeys[3].test(&ItemWorryLevel::N(4)), false);
+
+        assert_eq!(monkeys[2].get_destination(true), 1);
+        assert_eq!(monkeys[2].get_destination(false), 3);
+    }
+
+    #[test]
+    fn test_monkey_pool_executes_round() {
+        let mut pool = MonkeyPool::from_string(get_example_monkey_config(), false).unwrap();
+        assert_eq!(pool.n_monkeys(), 4);
+
+        pool.execute_round(true).unwrap();
+        let monkeys = pool.get_monkeys().clone();
+        assert_eq!(monkeys[0].get_items().into_iter().map(|i| i.unwrap()).collect::<Vec<u64>>(), vec![20, 23, 27, 26]);
+        assert_eq!(monkeys[1].get_items().into_iter().map(|i| i.unwrap()).collect::<Vec<u64>>(), vec![2080, 25, 167, 207, 401, 1046]);
+        assert_eq!(monkeys[2].get_items().len(), 0);
+        assert_eq!(monkeys[3].get_items().len(), 0);
+
+        pool.execute_round(true).unwrap();
+        let monkeys = pool.get_monkeys().clone();
+        assert_eq!(monkeys[0].get_items().into_iter().map(|i| i.unwrap()).collect::<Vec<u64>>(), vec![695, 10, 71, 135, 350]);
+        assert_eq!(monkeys[1].get_items().into_iter().map(|i| i.unwrap()).collect::<Vec<u64>>(), vec![43, 49, 58, 55, 362]);
+        assert_eq!(monkeys[2].get_items().len(), 0);
+        assert_eq!(monkeys[3].get_items().len(), 0);
+
+        pool.execute_round(true).unwrap();
+        let monkeys = pool.get_monkeys().clone();
+        assert_eq!(monkeys[0].get_items().into_iter().map(|i| i.unwrap()).collect::<Vec<u64>>(), vec![16, 18, 21, 20, 122]);
+        assert_eq!(monkeys[1].get_items().into_iter().map(|i| i.unwrap()).collect::<Vec<u64>>(), vec![1468, 22, 150, 286, 739]);
+        assert_eq!(monkeys[2].get_items().len(), 0);
+        assert_eq!(monkeys[3].get_items().len(), 0);
+
+        pool.execute_rounds(17, true).unwrap();
+        let monkeys = pool.get_monkeys().clone();
+        assert_eq!(monkeys[0].get_items().into_iter().map(|i| i.unwrap()).collect::<Vec<u64>>(), vec![10, 12, 14, 26, 34]);
+        assert_eq!(monkeys[1].get_items().into_iter().map(|i| i.unwrap()).collect::<Vec<u64>>(), vec![245, 93, 53, 199, 115]);
+        assert_eq!(monkeys[2].get_items().len(), 0);
+        assert_eq!(monkeys[3].get_items().len(), 0);
+
+    }
+
+
+    #[test]
+    fn test_monkey_pool_executes_round_with_moduli() {
+        let mut pool = MonkeyPool::from_string(get_example_monkey_config(), true).unwrap();
+        assert_eq!(pool.n_monkeys(), 4);
+
+        pool.execute_round(false).unwrap();
+        assert_eq!(pool.get_inspection_counts(), vec![2, 4, 3, 6]);
+
+        pool.execute_rounds(19, false).unwrap();
+        assert_eq!(pool.get_inspection_counts(), vec![99, 97, 8, 103]);
+
+    }
+
+    #[test]
+    fn test_monkey_pool_executes_round_equivalently_using_moduli_or_not() {
+        let mut pool_without = MonkeyPool::from_string(get_example_monkey_config(), false).unwrap();
+        let mut pool_with = pool_without.clone().modulize();
+
+        assert_eq!(pool_without.n_monkeys(), pool_with.n_monkeys());
+
+        for i in 0..4 {
+            pool_without.execute_round(false).unwrap();
+            pool_with.execute_round(false).unwrap();
+
+            let monkeys_without = pool_without.get_monkeys().clone();
+            let monkeys_with = pool_with.get_monkeys().clone();
+
+            for (monkey_without, monkey_with) in monkeys_without.into_iter().zip(monkeys_with) {
+                assert_eq!(monkey_without.operation, monkey_with.operation);
+                assert_eq!(monkey_without.test_divisor, monkey_with.test_divisor);
+                assert_eq!(monkey_without.true_destination, monkey_with.true_destination);
+                assert_eq!(monkey_without.false_destination, monkey_with.false_destination);
+                assert_eq!(monkey_without.items.len(), monkey_with.items.len());
+            }
+            assert_eq!(pool_without.get_inspection_counts(), pool_with.get_inspection_counts(), "Failed at {}", i);
+        }
+    }
+
+    #[test]
+    fn test_monkey_pool_modulizes() {
+        let mut pool = MonkeyPool::from_string(get_example_monkey_config(), true).unwrap();
+
+        assert_eq!(pool.n_monkeys(), 4);
+
+        pool.execute_round(false).unwrap();
+        let monkeys = pool.get_monkeys().clone();
+        assert_eq!(monkeys[2].get_items().len(), 0);
+        assert_eq!(monkeys[3].get_items().len(), 0);
+    }
+
+
+}
\ No newline at end of file
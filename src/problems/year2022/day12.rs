@@ -0,0 +1,277 @@
+use std::{collections::HashSet, str::FromStr};
+
+use crate::{input::input::InputParser, utility::vector::Vec2};
+use crate::utility::{grid, search};
+use crate::problems::solution::{Answer, Solution};
+use crate::utility::error::ParseError;
+
+pub fn solve_problem_12a(input: Vec<Vec<Square>>) -> Result<usize, ParseError> {
+    let grid = Grid::from_squares(input)?;
+    let shortest_path = grid.get_shortest_path_between(
+        false,
+        grid.get_starting_point(),
+        grid.get_ending_point()
+    );
+    let shortest_path = shortest_path.ok_or(ParseError::Malformed("There is no path from S to E.".to_string()))?;
+    return Ok(shortest_path.len() - 1);
+}
+
+pub fn solve_problem_12b(input: Vec<Vec<Square>>) -> Result<usize, ParseError> {
+    let grid = Grid::from_squares(input)?;
+    let shortest_path = grid.get_shortest_path(
+        true,
+        grid.get_ending_point(),
+        &|n| grid.get(n) == 0,
+    );
+    let shortest_path = shortest_path.ok_or(ParseError::Malformed("There is no path from any a to E.".to_string()))?;
+    return Ok(shortest_path.len() - 1);
+}
+
+#[derive(Default)]
+pub struct Problem12;
+
+impl Solution for Problem12 {
+    type Input = Vec<Vec<Square>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        InputParser::grid_as(InputParser::split_lines(input))
+    }
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_12a(input)?.into())
+    }
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_12b(input)?.into())
+    }
+}
+
+fn to_height(c: char) -> usize {
+    let to_return = (c as usize) - ('a' as usize);
+    assert!(to_return <= 25);
+    return to_return;
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Square {
+    Start,
+    End,
+    Height(usize),
+}
+
+impl Square {
+
+    pub fn get_height(&self) -> usize {
+        match self {
+            Self::Start => to_height('a'),
+            Self::End => to_height('z'),
+            Self::Height(height) => *height,
+        }
+    }
+}
+
+impl FromStr for Square {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "S" => Ok(Self::Start),
+            "E" => Ok(Self::End),
+            _ => match s.chars().next() {
+                Some(c) if s.len() == 1 && c.is_ascii_lowercase() => Ok(Self::Height(to_height(c))),
+                _ => Err("expected a height from a to z, S or E".to_string()),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid {
+    starting_point: Vec2,
+    ending_point: Vec2,
+    grid: grid::Grid<usize>,
+}
+
+impl Grid {
+
+    pub fn new(starting_point: Vec2, ending_point: Vec2, grid: grid::Grid<usize>) -> Self {
+        assert!(grid.is_in_bounds(starting_point));
+        assert!(grid.is_in_bounds(ending_point));
+        Self { starting_point, ending_point, grid }
+    }
+    
+    pub fn from_squares(squares: Vec<Vec<Square>>) -> Result<Self, ParseError> {
+        let find = |target: Square| squares.iter().enumerate().find_map(
+            |(y, row)| row.iter().position(|square| *square == target).map(|x| Vec2::new(x as i32, y as i32))
+        );
+        let starting_point = find(Square::Start).ok_or(ParseError::Malformed("The heightmap has no starting point S.".to_string()))?;
+        let ending_point = find(Square::End).ok_or(ParseError::Malformed("The heightmap has no ending point E.".to_string()))?;
+        let grid = grid::Grid::dense(squares.iter().map(|row| row.iter().map(|square| square.get_height()).collect()).collect())?;
+        Ok(Self::new(starting_point, ending_point, grid))
+    }
+
+    pub fn get(&self, v: Vec2) -> usize {
+        self.grid[v]
+    }
+
+    pub fn maybe_get(&self, v: Vec2) -> Option<usize> {
+        self.grid.get(v).copied()
+    }
+
+    pub fn is_in_bounds(&self, v: Vec2) -> bool {
+        self.grid.is_in_bounds(v)
+    }
+
+    pub fn get_dimensions(&self) -> (usize, usize) {
+        self.grid.get_dimensions()
+    }
+
+    pub fn get_starting_point(&self) -> Vec2 {
+        self.starting_point
+    }
+
+    pub fn get_ending_point(&self) -> Vec2 {
+        self.ending_point
+    }
+
+    pub fn get_coordinates(&self) -> Vec<Vec2> {
+        self.grid.get_positions()
+    }
+
+    pub fn get_neighbors(&self, coordinates: Vec2) -> HashSet<Vec2> {
+        let coordinates_height = self.get(coordinates);
+        self.get_adjacent(coordinates).into_iter().filter(|v| self.get(*v) as i32 - coordinates_height as i32 <= 1).collect()
+    }
+
+    pub fn get_inverse_neighbors(&self, coordinates: Vec2) -> HashSet<Vec2> {
+        let coordinates_height = self.get(coordinates);
+        self.get_adjacent(coordinates).into_iter().filter(|v| self.get(*v) as i32 - coordinates_height as i32 >= -1).collect()
+    }
+
+    pub fn get_adjacent(&self, coordinates: Vec2) -> HashSet<Vec2> {
+        self.grid.get_neighbors4(coordinates).into_iter().collect()
+    }
+
+    pub fn get_shortest_path_between(&self, use_inverse_neighbors: bool, start: Vec2, end: Vec2) -> Option<Vec<Vec2>> {
+        
+        self.get_shortest_path(use_inverse_neighbors, start, &(|n| n == end))
+
+    }
+
+    pub fn get_shortest_path(&self, use_inverse_neighbors: bool, start: Vec2, end_condition: &dyn Fn(Vec2) -> bool) -> Option<Vec<Vec2>> {
+        search::bfs(
+            start,
+            |node| if use_inverse_neighbors {
+                self.get_inverse_neighbors(*node)
+            } else {
+                self.get_neighbors(*node)
+            },
+            |node| end_condition(*node),
+        )
+    }
+
+}
+
+
+
+#[cfg(test)]
+mod test_problem_12 {
+
+    use crate::input::source::Source;
+    use crate::utility::vector::Vec2;
+
+    use super::*;
+
+    fn get_example_input() -> Vec<Vec<Square>> {
+        InputParser::new().parse_grid(Source::text("\
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi")).unwrap()
+    }
+
+    #[test]
+    fn test_problem_12a_passes() {
+        
+        assert_eq!(solve_problem_12a(get_example_input()).unwrap(), 31);
+
+        let input = InputParser::new().parse_grid("2022/input_12.txt").unwrap();
+
+        let answer = solve_problem_12a(input).unwrap();
+        assert_eq!(answer, 517);
+    }
+    
+    #[test]
+    fn test_problem_12b_passes() {
+
+        assert_eq!(solve_problem_12b(get_example_input()).unwrap(), 29);
+
+        let input = InputParser::new().parse_grid("2022/input_12.txt").unwrap();
+
+        let answer = solve_problem_12b(input).unwrap();
+        assert_eq!(answer, 512);
+    }
+
+    #[test]
+    fn test_gets_shortest_path() {
+        
+        let grid = Grid::from_squares(get_example_input()).unwrap();
+
+        assert_eq!(grid.get_shortest_path_between(false, Vec2::new(0, 0), Vec2::new(0, 0)), Some(vec![Vec2::new(0, 0)]));
+
+        assert_eq!(grid.get_shortest_path_between(false, Vec2::new(0, 0), Vec2::new(1, 0)), Some(vec![Vec2::new(0, 0), Vec2::new(1, 0)]));
+        assert_eq!(grid.get_shortest_path_between(false, Vec2::new(0, 0), Vec2::new(0, 1)), Some(vec![Vec2::new(0, 0), Vec2::new(0, 1)]));
+        assert_eq!(grid.get_shortest_path_between(false, Vec2::new(7, 1), Vec2::new(7, 0)), Some(vec![Vec2::new(7, 1), Vec2::new(7, 0)]));
+
+        assert_eq!(grid.get_shortest_path_between(false, Vec2::new(0, 0), Vec2::new(1, 1)).unwrap().len(), 3);
+
+        assert_eq!(grid.get_shortest_path_between(false, grid.get_starting_point(), grid.get_ending_point()).unwrap().len(), 32);
+
+    }
+
+    #[test]
+    fn test_get_neighbors() {
+
+        let grid = Grid::from_squares(get_example_input()).unwrap();
+
+        assert_eq!(
+            grid.get_neighbors(grid.get_starting_point()),
+            vec![Vec2::new(1, 0), Vec2::new(0, 1)].into_iter().collect()
+        );
+        assert_eq!(
+            grid.get_neighbors(grid.get_ending_point()),
+            vec![Vec2::new(4, 2), Vec2::new(6, 2), Vec2::new(5, 1), Vec2::new(5, 3)].into_iter().collect()
+        );
+        assert_eq!(
+            grid.get_neighbors(Vec2::new(2, 0)),
+            vec![Vec2::new(1, 0), Vec2::new(2, 1)].into_iter().collect()
+        );
+        
+    }
+
+    #[test]
+    fn test_parses_input() {
+        
+        let grid = Grid::from_squares(get_example_input()).unwrap();
+
+        assert_eq!(grid.get_starting_point(), Vec2::new(0, 0));
+        assert_eq!(grid.get(Vec2::new(0, 0)), to_height('a'));
+
+        assert_eq!(grid.get_ending_point(), Vec2::new(5, 2));
+        assert_eq!(grid.get(Vec2::new(5, 2)), to_height('z'));
+
+        assert_eq!(grid.get(Vec2::new(1, 2)), to_height('c'));
+        assert_eq!(grid.get(Vec2::new(2, 1)), to_height('c'));
+        assert_eq!(grid.get(Vec2::new(7, 4)), to_height('i'));
+
+    }
+
+    #[test]
+    fn test_to_height() {
+        assert_eq!(to_height('a'), 0);
+        assert_eq!(to_height('b'), 1);
+        assert_eq!(to_height('m'), 12);
+        assert_eq!(to_height('z'), 25);
+    }
+}
\ No newline at end of file
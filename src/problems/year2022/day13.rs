@@ -1,20 +1,27 @@
 use std::{collections::VecDeque, cmp::Ordering};
 
 use crate::{input::input::InputParser, utility::parser::Parser};
-
-pub fn solve_problem_13a(input: Vec<String>) -> usize {
-    let pairs = PacketPart::parse_packet_pairs(input);
-    pairs.into_iter().enumerate().map(
-        |(i, (left, right))| 
-            if PacketPart::is_ordered(&left, &right).unwrap() { i + 1 } else {0}
-        ).sum()
+use crate::problems::solution::{Answer, Solution};
+use crate::utility::error::ParseError;
+
+pub fn solve_problem_13a(input: Vec<String>) -> Result<usize, ParseError> {
+    let pairs = PacketPart::parse_packet_pairs(input)?;
+    let mut to_return = 0;
+    for (i, (left, right)) in pairs.into_iter().enumerate() {
+        match PacketPart::is_ordered(&left, &right) {
+            Some(true) => to_return += i + 1,
+            Some(false) => {},
+            None => return Err(ParseError::Malformed(format!("Pair {} has two identical packets.", i + 1))),
+        }
+    }
+    Ok(to_return)
 }
 
-fn solve_problem_13b(input: Vec<String>) -> usize {
+pub fn solve_problem_13b(input: Vec<String>) -> Result<usize, ParseError> {
 
-        let mut packets = PacketPart::from_strings(input.into_iter().filter(|x| x.len() > 0).collect());
-        let packet_2 = PacketPart::from_string("[[2]]".to_string());
-        let packet_6 = PacketPart::from_string("[[6]]".to_string());
+        let mut packets = PacketPart::from_strings(input)?;
+        let packet_2 = PacketPart::from_string("[[2]]".to_string())?;
+        let packet_6 = PacketPart::from_string("[[6]]".to_string())?;
         packets.push(packet_2.clone());
         packets.push(packet_6.clone());
 
@@ -23,12 +30,31 @@ fn solve_problem_13b(input: Vec<String>) -> usize {
         let idx_2 = packets.binary_search(&packet_2).expect("Packet 2 should definitely be in the packets.");
         let idx_6 = packets.binary_search(&packet_6).expect("Packet 6 should definitely be in the packets.");
 
-        return (idx_2 + 1) * (idx_6 + 1);
+        return Ok((idx_2 + 1) * (idx_6 + 1));
+
+}
+
+#[derive(Default)]
+pub struct Problem13;
+
+impl Solution for Problem13 {
+    type Input = Vec<String>;
 
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(InputParser::split_lines(input))
+    }
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_13a(input)?.into())
+    }
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_13b(input)?.into())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-enum PacketPart {
+pub enum PacketPart {
     List(Vec<PacketPart>),
     N(u32)
 }
@@ -51,37 +77,47 @@ impl PartialOrd for PacketPart {
 
 impl PacketPart {
 
-    pub fn parse_packet_pairs(strings: Vec<String>) -> Vec<(Self, Self)> {
-        InputParser::chunk(
-            strings.into_iter().filter(|l| l.chars().count() > 0).collect::<Vec<_>>(),
-            2
-        ).expect("The input should be in pairs separated by whitespace.")
-        .into_iter()
-        .map(|pair| (Self::from_string(pair[0].clone()), Self::from_string(pair[1].clone())))
-        .collect()
+    pub fn parse_packet_pairs(strings: Vec<String>) -> Result<Vec<(Self, Self)>, ParseError> {
+        InputParser::number_chunks(strings).into_iter().map(|chunk| {
+            let packets = chunk.into_iter()
+                .map(|(line_number, s)| Self::from_string(s).map_err(|e| e.offset_lines(line_number - 1)))
+                .collect::<Result<Vec<_>, ParseError>>()?;
+            match <[Self; 2]>::try_from(packets) {
+                Ok([left, right]) => Ok((left, right)),
+                Err(packets) => Err(ParseError::Malformed(format!("Expected packets to come in pairs but got a group of {}.", packets.len()))),
+            }
+        }).collect()
     }
 
-    pub fn from_strings(strings: Vec<String>) -> Vec<Self> {
-        strings.into_iter().map(|s| Self::from_string(s)).collect()
+    // Blank lines between packets are skipped.
+    pub fn from_strings(strings: Vec<String>) -> Result<Vec<Self>, ParseError> {
+        strings.into_iter().enumerate()
+            .filter(|(_, s)| !s.is_empty())
+            .map(|(i, s)| Self::from_string(s).map_err(|e| e.offset_lines(i)))
+            .collect()
     }
 
-    pub fn from_string(s: String) -> Self {
+    pub fn from_string(s: String) -> Result<Self, ParseError> {
         let mut parser = Parser::new(s);
-        let tokens = Self::tokenize(parser);
-        Self::from_tokens(tokens).0
+        let tokens = Self::tokenize(parser)?;
+        let (to_return, remaining) = Self::from_tokens(tokens)?;
+        if !remaining.is_empty() {
+            return Err(ParseError::invalid(1, 1, format!("Unexpected tokens after the end of the packet: {:?}", remaining)));
+        }
+        Ok(to_return)
     }
 
-    fn tokenize(mut parser: Parser) -> VecDeque<Token> {
+    fn tokenize(mut parser: Parser) -> Result<VecDeque<Token>, ParseError> {
         let mut to_return = VecDeque::new();
-        while let Some(token) = Self::get_next_token(&mut parser) {
+        while let Some(token) = Self::get_next_token(&mut parser)? {
             to_return.push_back(token)
         }
-        return to_return;
+        return Ok(to_return);
     }
 
-    fn get_next_token(parser: &mut Parser) -> Option<Token> {
+    fn get_next_token(parser: &mut Parser) -> Result<Option<Token>, ParseError> {
         if parser.n_remaining_to_parse() == 0 {
-            return None;
+            return Ok(None);
         }
         let token = match parser.peek_char().unwrap() {
             '[' => {
@@ -96,37 +132,36 @@ impl PacketPart {
                 Token::RightBracket
             },
             '0' | '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' => {
-                Self::tokenize_number(parser)
+                Self::tokenize_number(parser)?
             },
-            c => panic!("Unexpected character: {}", c)
+            c => return Err(parser.unexpected_token("[, ] or a number", &c.to_string()))
         };
-        return Some(token);
+        return Ok(Some(token));
     }
 
-    fn tokenize_number(parser: &mut Parser) -> Token {
-        let s = parser.consume_until(&|c| c == ',' || c == ']');
-        let to_return = Token::N(s.parse::<u32>().expect(&format!("Number should be parsable into a u32 but was {}", s)));
+    fn tokenize_number(parser: &mut Parser) -> Result<Token, ParseError> {
+        let to_return = Token::N(parser.consume_number::<u32>(&|c| c == ',' || c == ']')?);
         if parser.peek_char() == Some(',') {
             let _comma = parser.consume_n(1);
         }
-        return to_return;
+        return Ok(to_return);
     }
 
-    fn from_tokens(mut tokens: VecDeque<Token>) -> (Self, VecDeque<Token>) {
-        match tokens.pop_front().expect("There should be at least one element in tokens.") {
-            Token::N(n) => (Self::N(n), tokens),
-            Token::LeftBracket => {
+    fn from_tokens(mut tokens: VecDeque<Token>) -> Result<(Self, VecDeque<Token>), ParseError> {
+        match tokens.pop_front() {
+            Some(Token::N(n)) => Ok((Self::N(n), tokens)),
+            Some(Token::LeftBracket) => {
                 let mut to_return = Vec::new();
                 while tokens.get(0) != Some(&Token::RightBracket) {
-                    let (parsed, new_tokens) = Self::from_tokens(tokens);
+                    let (parsed, new_tokens) = Self::from_tokens(tokens)?;
                     to_return.push(parsed);
                     tokens = new_tokens;
                 }
-                let right_bracket = tokens.pop_front();
-                assert_eq!(right_bracket, Some(Token::RightBracket));
-                return (Self::List(to_return), tokens);
+                let _right_bracket = tokens.pop_front();
+                return Ok((Self::List(to_return), tokens));
             },
-            Token::RightBracket => panic!("Got a right bracket when parsing.")
+            Some(Token::RightBracket) => Err(ParseError::invalid(1, 1, "Got a right bracket without a matching left bracket.".to_string())),
+            None => Err(ParseError::invalid(1, 1, "The packet ended before all of its brackets were closed.".to_string())),
         }
     }
 
@@ -163,7 +198,7 @@ impl PacketPart {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-enum Token {
+pub enum Token {
     LeftBracket,
     RightBracket,
     N(u32),
@@ -177,32 +212,32 @@ mod test_problem_13 {
     #[test]
     fn test_problem_13a_passes() {
         
-        let example_input = InputParser::new().parse_as_string("example_input_13.txt").unwrap();
-        assert_eq!(solve_problem_13a(example_input), 13);
+        let example_input = InputParser::new().parse_as_string("2022/example_input_13.txt").unwrap();
+        assert_eq!(solve_problem_13a(example_input).unwrap(), 13);
 
-        let input = InputParser::new().parse_as_string("input_13.txt").unwrap();
+        let input = InputParser::new().parse_as_string("2022/input_13.txt").unwrap();
 
-        let answer = solve_problem_13a(input);
+        let answer = solve_problem_13a(input).unwrap();
         assert_eq!(answer, 5340);
     }
     
     #[test]
     fn test_problem_13b_passes() {
-        let example_input = InputParser::new().parse_as_string("example_input_13.txt").unwrap();
-        assert_eq!(solve_problem_13b(example_input), 140);
+        let example_input = InputParser::new().parse_as_string("2022/example_input_13.txt").unwrap();
+        assert_eq!(solve_problem_13b(example_input).unwrap(), 140);
 
-        let input = InputParser::new().parse_as_string("input_13.txt").unwrap();
+        let input = InputParser::new().parse_as_string("2022/input_13.txt").unwrap();
 
-        let answer = solve_problem_13b(input);
+        let answer = solve_problem_13b(input).unwrap();
         assert_eq!(answer, 21276);
     }
 
     #[test]
     fn test_sorts_correctly() {
         
-        let example_input = InputParser::new().parse_as_string("example_input_13.txt").unwrap();
+        let example_input = InputParser::new().parse_as_string("2022/example_input_13.txt").unwrap();
 
-        let mut packets = PacketPart::from_strings(example_input.into_iter().filter(|x| x.len() > 0).collect());
+        let mut packets = PacketPart::from_strings(example_input.into_iter().filter(|x| x.len() > 0).collect()).unwrap();
         packets.sort();
 
         let expected = vec![
@@ -223,16 +258,16 @@ mod test_problem_13 {
             "[[8,7,6]]".to_string(),
             "[9]".to_string(),
         ];
-        assert_eq!(packets, PacketPart::from_strings(expected));
+        assert_eq!(packets, PacketPart::from_strings(expected).unwrap());
 
     }
 
     #[test]
     fn test_parses_correctly() {
 
-        let example_input = InputParser::new().parse_as_string("example_input_13.txt").unwrap();
+        let example_input = InputParser::new().parse_as_string("2022/example_input_13.txt").unwrap();
 
-        let packet_pairs = PacketPart::parse_packet_pairs(example_input);
+        let packet_pairs = PacketPart::parse_packet_pairs(example_input).unwrap();
         assert_eq!(packet_pairs.len(), 8);
         assert_eq!(packet_pairs[5], (PacketPart::List(vec![]), PacketPart::List(vec![PacketPart::N(3)])));
         
@@ -258,10 +293,10 @@ mod test_problem_13 {
 
     #[test]
     fn test_tokenizes_correctly() {
-        assert_eq!(PacketPart::tokenize(Parser::new("1".to_string())), vec![Token::N(1)]);
-        assert_eq!(PacketPart::tokenize(Parser::new("0190".to_string())), vec![Token::N(190)]);
-        assert_eq!(PacketPart::tokenize(Parser::new("[190]".to_string())), vec![Token::LeftBracket, Token::N(190), Token::RightBracket]);
-        assert_eq!(PacketPart::tokenize(Parser::new("[1,2,3]".to_string())), vec![Token::LeftBracket, Token::N(1), Token::N(2), Token::N(3), Token::RightBracket]);
+        assert_eq!(PacketPart::tokenize(Parser::new("1".to_string())).unwrap(), vec![Token::N(1)]);
+        assert_eq!(PacketPart::tokenize(Parser::new("0190".to_string())).unwrap(), vec![Token::N(190)]);
+        assert_eq!(PacketPart::tokenize(Parser::new("[190]".to_string())).unwrap(), vec![Token::LeftBracket, Token::N(190), Token::RightBracket]);
+        assert_eq!(PacketPart::tokenize(Parser::new("[1,2,3]".to_string())).unwrap(), vec![Token::LeftBracket, Token::N(1), Token::N(2), Token::N(3), Token::RightBracket]);
     }
 
 }
\ No newline at end of file
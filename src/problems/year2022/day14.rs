@@ -1,30 +1,88 @@
-use std::collections::{HashMap, HashSet};
+use std::{collections::{HashMap, HashSet}, fmt, str::FromStr};
 
 use crate::input::input::InputParser;
+use crate::utility::grid::Grid;
 use crate::utility::vector::Vec2;
+use crate::problems::solution::{Answer, Solution};
+use crate::utility::error::ParseError;
 
-pub fn solve_problem_14a(input: Vec<String>) -> usize {
-    let mut cave = Cave::from_strings(input, None);
+pub fn solve_problem_14a(input: Vec<RockPath>) -> Result<usize, ParseError> {
+    let mut cave = Cave::from_rock_paths(input, None)?;
     let sand_entry_location = Vec2::new(500, 0);
     
     cave.drop_sand_until_abyss(&sand_entry_location);
 
-    cave.count_non_abyss_sand()
+    Ok(cave.count_non_abyss_sand())
 }
 
-fn solve_problem_14b(input: Vec<String>) -> usize {
-    let mut cave = Cave::from_strings(input, Some(2));
+pub fn solve_problem_14b(input: Vec<RockPath>) -> Result<usize, ParseError> {
+    let mut cave = Cave::from_rock_paths(input, Some(2))?;
     let sand_entry_location = Vec2::new(500, 0);
     
     cave.drop_sand_until_abyss(&sand_entry_location);
     assert!(!cave.has_hit_abyss);
 
-    cave.count_non_abyss_sand()
+    Ok(cave.count_non_abyss_sand())
+}
+
+#[derive(Default)]
+pub struct Problem14;
+
+impl Solution for Problem14 {
+    type Input = Vec<RockPath>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        InputParser::lines_as(InputParser::split_lines(input))
+    }
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_14a(input)?.into())
+    }
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_14b(input)?.into())
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-struct Cave {
-    material_map: HashMap<Vec2, Material>,
+pub struct RockPath {
+    endpoints: Vec<Vec2>,
+}
+
+impl RockPath {
+
+    pub fn get_points(&self) -> HashSet<Vec2> {
+        self.endpoints.iter().zip(self.endpoints.iter().skip(1))
+            .flat_map(|(p, q)| Vec2::get_points_between(*p, *q))
+            .collect()
+    }
+}
+
+impl FromStr for RockPath {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let endpoints = s.split(" -> ").map(|point| {
+            let (x, y) = point.split_once(',').ok_or(format!("expected a point like 498,4 but got {:?}", point))?;
+            match (x.parse::<i32>(), y.parse::<i32>()) {
+                (Ok(x), Ok(y)) => Ok(Vec2::new(x, y)),
+                _ => Err(format!("expected a point like 498,4 but got {:?}", point)),
+            }
+        }).collect::<Result<Vec<_>, String>>()?;
+
+        for (p, q) in endpoints.iter().zip(endpoints.iter().skip(1)) {
+            let delta = *q - *p;
+            if delta.x() != 0 && delta.y() != 0 && delta.x().abs() != delta.y().abs() {
+                return Err(format!("the rock path from {:?} to {:?} isn't a straight line", p, q));
+            }
+        }
+        Ok(Self { endpoints })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cave {
+    material_map: Grid<Material>,
     lowest_points: HashMap<i32, i32>,
     floor_y: Option<i32>,
     has_hit_abyss: bool,
@@ -33,12 +91,12 @@ struct Cave {
 
 impl Cave {
 
-    pub fn new(material_map: HashMap<Vec2, Material>, lowest_points: HashMap<i32, i32>, floor_y: Option<i32>) -> Self {
+    pub fn new(material_map: Grid<Material>, lowest_points: HashMap<i32, i32>, floor_y: Option<i32>) -> Self {
         Self { material_map, lowest_points, floor_y, has_hit_abyss: false, has_blocked_starting_point: false }
     }
 
-    pub fn from_strings(strings: Vec<String>, floor_delta_y: Option<i32>) -> Self {
-        let rock_points: HashSet<Vec2> = strings.into_iter().map(|s| Self::lines_to_points(s)).flatten().collect();
+    pub fn from_rock_paths(rock_paths: Vec<RockPath>, floor_delta_y: Option<i32>) -> Result<Self, ParseError> {
+        let rock_points: HashSet<Vec2> = rock_paths.iter().flat_map(|path| path.get_points()).collect();
         let lowest_points = rock_points.clone().into_iter().map(|p| (p.x(), p.y())).fold(HashMap::new(), |mut accumulator, element| {
             if accumulator.contains_key(&element.0) {
                 if &element.1 > accumulator.get(&element.0).unwrap() { // Note that > corresponds to lower!
@@ -49,34 +107,15 @@ impl Cave {
             }
             accumulator
         });
-        let lowest_point = lowest_points.iter().map(|p| *p.1).max().expect("We should always have at least one lowest point.");
+        let lowest_point = lowest_points.iter().map(|p| *p.1).max()
+            .ok_or(ParseError::Malformed("The scan should have at least one rock.".to_string()))?;
         let floor_y = floor_delta_y.map(|delta_y| lowest_point + delta_y);
-        let material_map = rock_points.into_iter().map(|p| (p, Material::Rock)).collect();
-        Self::new(material_map, lowest_points, floor_y)
-    }
-
-    fn lines_to_points(lines: String) -> HashSet<Vec2> {
-        let endpoints: Vec<_> = lines
-            .split(" -> ")
-            .map(|s| s.split(",").collect::<Vec<_>>())
-            .map(|coords| {
-                assert_eq!(coords.len(), 2);
-                Vec2::new(
-                    coords[0].parse::<i32>().expect("x should parse to i32"),
-                    coords[1].parse::<i32>().expect("y should parse to i32")
-                )
-            }).collect();
-
-        endpoints.clone().into_iter().zip(endpoints.into_iter().skip(1))
-            .map(|(p, q)| Vec2::get_points_between(p, q))
-            .flatten().collect::<HashSet<Vec2>>()
+        let material_map = Grid::from_cells(rock_points.into_iter().map(|p| (p, Material::Rock)), Material::Air);
+        Ok(Self::new(material_map, lowest_points, floor_y))
     }
 
     pub fn get(&self, v: &Vec2) -> Material {
-        match self.material_map.get(v) {
-            None => Material::Air,
-            Some(m) => *m,
-        }
+        self.material_map[*v]
     }
 
     pub fn drop_sand_until_abyss(&mut self, starting_position: &Vec2) {
@@ -97,7 +136,7 @@ impl Cave {
             match self.get_next_sand_position(sand_position) {
                 Some(v) => {
                     if sand_position == v {
-                        self.material_map.insert(sand_position, Material::Sand);
+                        self.material_map.set(sand_position, Material::Sand);
                         break;
                     }
                     sand_position = v;
@@ -156,55 +195,74 @@ impl Cave {
     }
 
     pub fn count_non_abyss_sand(&self) -> usize {
-        self.material_map.values().filter(|m| m == &&Material::Sand).count()
+        self.material_map.iter().filter(|(_, m)| **m == Material::Sand).count()
     }
 
 }
+
+impl fmt::Display for Cave {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.material_map.render(|m| m.to_char()))
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-enum Material {
+pub enum Material {
     Air,
     Rock,
     Sand,
 }
 
+impl Material {
+
+    pub fn to_char(&self) -> char {
+        match self {
+            Self::Air => '.',
+            Self::Rock => '#',
+            Self::Sand => 'o',
+        }
+    }
+}
+
 #[cfg(test)]
 mod test_problem_14 {
 
+    use crate::input::source::Source;
+
     use super::*;
 
-    fn get_example_input() -> Vec<String> {
-        vec![
-            "498,4 -> 498,6 -> 496,6".to_string(),
-            "503,4 -> 502,4 -> 502,9 -> 494,9".to_string(),
-        ]
+    fn get_example_input() -> Vec<RockPath> {
+        InputParser::new().parse_lines(Source::text("\
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9")).unwrap()
     }
 
     #[test]
     fn test_problem_14a_passes() {
         
-        assert_eq!(solve_problem_14a(get_example_input()), 24);
+        assert_eq!(solve_problem_14a(get_example_input()).unwrap(), 24);
 
-        let input = InputParser::new().parse_as_string("input_14.txt").unwrap();
+        let input = InputParser::new().parse_lines("2022/input_14.txt").unwrap();
 
-        let answer = solve_problem_14a(input);
+        let answer = solve_problem_14a(input).unwrap();
         assert_eq!(answer, 763);
     }
     
     #[test]
     fn test_problem_14b_passes() {
 
-        assert_eq!(solve_problem_14b(get_example_input()), 93);
+        assert_eq!(solve_problem_14b(get_example_input()).unwrap(), 93);
 
-        let input = InputParser::new().parse_as_string("input_14.txt").unwrap();
+        let input = InputParser::new().parse_lines("2022/input_14.txt").unwrap();
 
-        let answer = solve_problem_14b(input);
+        let answer = solve_problem_14b(input).unwrap();
         assert_eq!(answer, 23921);
     }
 
     #[test]
     fn test_simulates_sand_drops() {
         
-        let mut cave = Cave::from_strings(get_example_input(), None);
+        let mut cave = Cave::from_rock_paths(get_example_input(), None).unwrap();
         let sand_entry_location = Vec2::new(500, 0);
         cave.drop_sand(&sand_entry_location);
 
@@ -227,10 +285,33 @@ mod test_problem_14 {
 
     }
 
+    #[test]
+    fn test_draws_cave() {
+
+        let mut cave = Cave::from_rock_paths(get_example_input(), None).unwrap();
+        assert_eq!(cave.to_string(), "\
+....#...##
+....#...#.
+..###...#.
+........#.
+........#.
+#########.");
+
+        cave.drop_sand(&Vec2::new(500, 0));
+        cave.drop_sand(&Vec2::new(500, 0));
+        assert_eq!(cave.to_string(), "\
+....#...##
+....#...#.
+..###...#.
+........#.
+.....oo.#.
+#########.");
+    }
+
     #[test]
     fn test_simulates_sand_drops_with_floor() {
         
-        let mut cave = Cave::from_strings(get_example_input(), Some(2));
+        let mut cave = Cave::from_rock_paths(get_example_input(), Some(2)).unwrap();
         let sand_entry_location = Vec2::new(500, 0);
         cave.drop_sand(&sand_entry_location);
 
@@ -252,7 +333,7 @@ mod test_problem_14 {
     #[test]
     fn test_computes_abyss_correctly() {
         
-        let cave = Cave::from_strings(get_example_input(), None);
+        let cave = Cave::from_rock_paths(get_example_input(), None).unwrap();
 
         assert!(cave.is_in_abyss(&Vec2::new(493, 0)));
         assert!(cave.is_in_abyss(&Vec2::new(504, 0)));
@@ -265,4 +346,15 @@ mod test_problem_14 {
         assert!(!cave.is_in_abyss(&Vec2::new(503, 4)));
     }
 
+    #[test]
+    fn test_rock_paths_report_bad_input() {
+        let input = vec![
+            "498,4 -> 498,6 -> 496,6".to_string(),
+            "503,4 -> 502,4 -> 501,9".to_string(),
+        ];
+        let error = InputParser::lines_as::<RockPath>(input).unwrap_err();
+        assert!(error.to_string().starts_with("Line 2, column 1: Unable to parse"), "{}", error);
+        assert!(error.to_string().ends_with("isn't a straight line"), "{}", error);
+    }
+
 }
\ No newline at end of file
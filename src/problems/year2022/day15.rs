@@ -0,0 +1,241 @@
+use std::collections::HashSet;
+
+use crate::{input::input::InputParser, utility::{vector::Vec2I64, parser::Parser}};
+use crate::problems::solution::{Answer, Parameter, Solution};
+use crate::utility::error::ParseError;
+
+pub fn solve_problem_15a(input: Vec<String>, row_y: i64) -> Result<usize, ParseError> {
+    let sensors = Sensor::from_strings(input)?;
+    let (min_x, max_x) = sensors.iter().map(|s| s.get_x_bounds()).reduce(|mut accumulator, element| {
+            let (l, r) = element;
+            accumulator.0 = if l < accumulator.0 { l } else { accumulator.0 };
+            accumulator.1 = if r > accumulator.1 { r } else { accumulator.1 };
+            accumulator
+        }
+    ).ok_or(ParseError::Malformed("There should be at least one sensor.".to_string()))?;
+    let impossible_positions = (min_x..=max_x)
+        .filter(
+            |x| sensors.iter().any(
+                |sensor| !sensor.beacon_is_possible(Vec2I64::new(*x, row_y))
+            )
+        ).collect::<Vec<_>>();
+    return Ok(impossible_positions.len());
+}
+
+pub fn solve_problem_15b(input: Vec<String>, search_space_size: usize) -> Result<u64, ParseError> {
+    let sensors = Sensor::from_strings(input)?;
+    for s in sensors.iter() {
+        let constraint = |v: Vec2I64| {
+            0 <= v.x() &&
+            v.x() <= search_space_size as i64 &&
+            0 <= v.y() &&
+            v.y() <= search_space_size as i64
+        };
+        let surface = s.get_surface_such_that(&constraint);
+        for position in surface.iter() {
+            // Because the point is unique, it's guaranteed to be on the "surface" of one of the sensors.
+            // Proof: Suppose it's not on the surface, then there are no sensors within 1 of it, but that means
+            // that moving one over, you get another answer, violating uniqueness.
+            let hit = sensors.iter().all(
+                |sensor| sensor.beacon_is_possible(*position) && sensor.beacon_position != *position
+            );
+            if hit {
+                return get_tuning_frequency(*position);
+            }
+        }
+    }
+    Err(ParseError::Malformed(format!("No position within 0..={} is out of range of every sensor.", search_space_size)))
+}
+
+fn get_tuning_frequency(position: Vec2I64) -> Result<u64, ParseError> {
+    position.x().checked_mul(4_000_000).and_then(|x| x.checked_add(position.y()))
+        .and_then(|frequency| u64::try_from(frequency).ok())
+        .ok_or(ParseError::Malformed(format!("The tuning frequency of {:?} doesn't fit in a u64.", position)))
+}
+
+pub struct Problem15 {
+    pub row_y: i64,
+    pub search_space_size: usize,
+}
+
+impl Default for Problem15 {
+    fn default() -> Self {
+        Self { row_y: 2_000_000, search_space_size: 4_000_000 }
+    }
+}
+
+impl Solution for Problem15 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(InputParser::split_lines(input))
+    }
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_15a(input, self.row_y)?.into())
+    }
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_15b(input, self.search_space_size)?.into())
+    }
+
+    fn get_parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::new("row_y", self.row_y, "Row to count positions without a beacon in for part a."),
+            Parameter::new("search_space_size", self.search_space_size, "Largest x and y to search for the distress beacon in part b."),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "row_y" => self.row_y = Parameter::parse(name, value)?,
+            "search_space_size" => self.search_space_size = Parameter::parse(name, value)?,
+            _ => return Err(format!("Unknown parameter: {}", name)),
+        }
+        Ok(())
+    }
+}
+
+pub struct Sensor {
+    position: Vec2I64,
+    beacon_position: Vec2I64,
+    max_beacon_distance: u64,
+}
+
+impl Sensor {
+
+    pub fn new(position: Vec2I64, beacon_position: Vec2I64) -> Self {
+        let max_beacon_distance = (position - beacon_position).get_l1_norm();
+        Self { position, beacon_position, max_beacon_distance }
+    }
+
+    pub fn from_strings(strings: Vec<String>) -> Result<Vec<Self>, ParseError> {
+        let mut to_return: Vec<Self> = InputParser::parse_each_line(strings, Self::from_string)?;
+        to_return.sort_by(|a, b| a.max_beacon_distance.cmp(&b.max_beacon_distance).reverse());
+        return Ok(to_return);
+    }
+
+    pub fn from_string(s: String) -> Result<Self, ParseError> {
+        Parser::parse_all(&s, |parser| {
+            parser.tag("Sensor at ")?;
+            let sensor_position = Self::parse_vec2(parser)?;
+            parser.tag(": closest beacon is at ")?;
+            let beacon_position = Self::parse_vec2(parser)?;
+            Ok(Self::new(sensor_position, beacon_position))
+        })
+    }
+
+    fn parse_vec2(parser: &mut Parser) -> Result<Vec2I64, ParseError> {
+        parser.tag("x=")?;
+        let x = parser.integer::<i64>()?;
+        parser.tag(", y=")?;
+        let y = parser.integer::<i64>()?;
+        return Ok(Vec2I64::new(x, y));
+    }
+
+    pub fn beacon_is_possible(&self, position: Vec2I64) -> bool {
+        if position == self.beacon_position {
+            return true;
+        }
+        (position - self.position).get_l1_norm() > self.max_beacon_distance
+    }
+
+    pub fn get_x_bounds(&self) -> (i64, i64) {
+        (self.position.x() - self.max_beacon_distance as i64, self.position.x() + self.max_beacon_distance as i64)
+    }
+
+    pub fn get_y_bounds(&self) -> (i64, i64) {
+        (self.position.y() - self.max_beacon_distance as i64, self.position.y() + self.max_beacon_distance as i64)
+    }
+    
+    pub fn get_surface_such_that(&self, constraint: &dyn Fn(Vec2I64) -> bool) -> HashSet<Vec2I64> {
+        let r = (self.max_beacon_distance + 1) as i64;
+        let right = self.position + Vec2I64::i() * r;
+        let left = self.position - Vec2I64::i() * r;
+        let bottom = self.position + Vec2I64::j() * r;
+        let top = self.position - Vec2I64::j() * r;
+
+        let top_left_quadrant = Vec2I64::get_points_between(left, top).into_iter().filter(|v| constraint(*v)).collect::<HashSet<_>>();
+        let top_right_quadrant = Vec2I64::get_points_between(top, right).into_iter().filter(|v| constraint(*v)).collect::<HashSet<_>>();
+        let bottom_left_quadrant = Vec2I64::get_points_between(bottom, left).into_iter().filter(|v| constraint(*v)).collect::<HashSet<_>>();
+        let bottom_right_quadrant = Vec2I64::get_points_between(bottom, right).into_iter().filter(|v| constraint(*v)).collect::<HashSet<_>>();
+
+        return top_left_quadrant
+            .union(&top_right_quadrant).cloned().collect::<HashSet<_>>()
+            .union(&bottom_left_quadrant).cloned().collect::<HashSet<_>>()
+            .union(&bottom_right_quadrant).cloned().collect::<HashSet<_>>();
+    }
+
+}
+
+#[cfg(test)]
+mod test_problem_15 {
+
+    use crate::input::{profile::Profile, source::Source};
+
+    use super::*;
+
+    fn get_example_input() -> Vec<String> {
+        InputParser::new().parse_as_string(Source::Profile { year: 2022, day: 15, profile: Profile::Example }).unwrap()
+    }
+
+    #[test]
+    fn test_problem_15a_passes() {
+
+        let example_input = get_example_input();
+        assert_eq!(solve_problem_15a(example_input, 10).unwrap(), 26);
+        
+        let input = InputParser::new().parse_as_string("2022/input_15.txt").unwrap();
+        
+        let answer = solve_problem_15a(input, 2_000_000).unwrap();
+        assert_eq!(answer, 4811413);
+    }
+    
+    #[test]
+    fn test_problem_15b_passes() {
+
+        let example_input = get_example_input();
+        assert_eq!(solve_problem_15b(example_input, 20).unwrap(), 56_000_011);
+
+        let input = InputParser::new().parse_as_string("2022/input_15.txt").unwrap();
+
+        let answer = solve_problem_15b(input, 4_000_000).unwrap();
+        assert_eq!(answer, 0);
+    }
+
+    #[test]
+    fn test_sensors_query() {
+        
+        let sensors = Sensor::from_strings(get_example_input()).unwrap();
+        assert_eq!(sensors.len(), 14);
+
+        assert!(!sensors[1].beacon_is_possible(Vec2I64::new(8, 7)));
+        assert!(!sensors[1].beacon_is_possible(Vec2I64::new(8, 8)));
+        assert!(!sensors[1].beacon_is_possible(Vec2I64::new(7, 7)));
+        assert!(!sensors[1].beacon_is_possible(Vec2I64::new(-1, 7)));
+        assert!(!sensors[1].beacon_is_possible(Vec2I64::new(8, -2)));
+
+        assert!(sensors[1].beacon_is_possible(Vec2I64::new(9, -2)));
+        assert!(sensors[1].beacon_is_possible(Vec2I64::new(7, -2)));
+        assert!(sensors[1].beacon_is_possible(Vec2I64::new(8, -3)));
+
+    }
+
+    #[test]
+    fn test_gets_tuning_frequency() {
+        assert_eq!(get_tuning_frequency(Vec2I64::new(14, 11)).unwrap(), 56_000_011);
+        assert_eq!(get_tuning_frequency(Vec2I64::new(4_000_000, 4_000_000)).unwrap(), 16_000_004_000_000);
+        assert!(get_tuning_frequency(Vec2I64::new(i64::MAX / 1_000_000, 0)).is_err());
+        assert!(get_tuning_frequency(Vec2I64::new(-1, 0)).is_err());
+    }
+
+    #[test]
+    fn test_sensors_report_bad_input() {
+        let mut input = get_example_input();
+        input[3] = "Sensor at x=12, y=14: closest beacon is at x=10, y=1six".to_string();
+
+        let error = Sensor::from_strings(input).err().unwrap();
+        assert_eq!(error.to_string(), "Line 4, column 53: expected \"end of input\", but got \"six\"");
+    }
+
+}
\ No newline at end of file
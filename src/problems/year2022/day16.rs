@@ -2,6 +2,10 @@ use std::{collections::{HashMap, HashSet}, thread::current};
 use itertools::Itertools;
 
 use crate::input::input::InputParser;
+use crate::problems::solution::{Answer, Parameter, Solution};
+use crate::utility::error::ParseError;
+use crate::utility::parser::Parser;
+use crate::utility::search;
 
 type Time = usize;
 
@@ -9,46 +13,84 @@ const INITIAL_MINUTES_UNTIL_ERUPTION: Time = 30;
 const MINUTES_SPENT_TEACHING_ELEPHANT: Time = 4;
 const DISTANCE_BIGGER_THAN_MAX: usize = 1000;
 
-pub fn solve_problem_16a(input: Vec<String>) -> u32 {
+pub fn solve_problem_16a(input: Vec<String>, minutes_until_eruption: Time) -> Result<u32, ParseError> {
 
-        let valves = Valve::from_strings(input);
+        let valves = Valve::from_strings(input)?;
         let graph = ValveGraph::new(valves.clone());
+        let starting_index = get_starting_index(&graph)?;
 
         let nodes_with_flow = (0..valves.len()).into_iter().filter(|i| valves[*i].get_flow_rate() > 0).collect::<HashSet<_>>();
-        graph.get_maximum_flow(&nodes_with_flow, INITIAL_MINUTES_UNTIL_ERUPTION, graph.get_index("AA").unwrap(), 0)
+        Ok(graph.get_maximum_flow(&nodes_with_flow, minutes_until_eruption, starting_index, 0))
 }
 
-fn solve_problem_16b(input: Vec<String>) -> u32 {
-        let valves = Valve::from_strings(input);
+pub fn solve_problem_16b(input: Vec<String>, minutes_until_eruption: Time, minutes_spent_teaching_elephant: Time) -> Result<u32, ParseError> {
+        let valves = Valve::from_strings(input)?;
         let graph = ValveGraph::new(valves.clone());
+        let starting_index = get_starting_index(&graph)?;
 
         let nodes_with_flow = (0..valves.len()).into_iter().filter(|i| valves[*i].get_flow_rate() > 0).collect::<HashSet<_>>();
 
-        let time_remaining = INITIAL_MINUTES_UNTIL_ERUPTION - MINUTES_SPENT_TEACHING_ELEPHANT;
+        let time_remaining = minutes_until_eruption.checked_sub(minutes_spent_teaching_elephant)
+            .ok_or(ParseError::Malformed("The volcano erupts before the elephant has been taught.".to_string()))?;
 
-        graph.get_maximum_flow_with_elephant(
+        Ok(graph.get_maximum_flow_with_elephant(
             &nodes_with_flow,
             time_remaining,
-            Plan::new(graph.get_index("AA").unwrap(), 0),
-            Plan::new(graph.get_index("AA").unwrap(), 0),
+            Plan::new(starting_index, 0),
+            Plan::new(starting_index, 0),
             0
-        )
+        ))
+}
+
+fn get_starting_index(graph: &ValveGraph) -> Result<usize, ParseError> {
+    graph.get_index("AA").ok_or(ParseError::Malformed("There is no valve AA to start from.".to_string()))
 }
 
-fn get_minutes_until_eruption(t: usize) -> usize {
-    INITIAL_MINUTES_UNTIL_ERUPTION - t
+pub struct Problem16 {
+    pub minutes_until_eruption: Time,
+    pub minutes_spent_teaching_elephant: Time,
 }
 
-fn get_causal_radius(t: usize) -> usize {
-    assert!(t < INITIAL_MINUTES_UNTIL_ERUPTION);
-    match get_minutes_until_eruption(t) {
-        0..=1 => 0,
-        n => n - 2,
+impl Default for Problem16 {
+    fn default() -> Self {
+        Self { minutes_until_eruption: INITIAL_MINUTES_UNTIL_ERUPTION, minutes_spent_teaching_elephant: MINUTES_SPENT_TEACHING_ELEPHANT }
+    }
+}
+
+impl Solution for Problem16 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(InputParser::split_lines(input))
+    }
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_16a(input, self.minutes_until_eruption)?.into())
+    }
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_16b(input, self.minutes_until_eruption, self.minutes_spent_teaching_elephant)?.into())
+    }
+
+    fn get_parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::new("minutes_until_eruption", self.minutes_until_eruption, "Minutes before the volcano erupts."),
+            Parameter::new("minutes_spent_teaching_elephant", self.minutes_spent_teaching_elephant, "Minutes spent teaching the elephant in part b."),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "minutes_until_eruption" => self.minutes_until_eruption = Parameter::parse(name, value)?,
+            "minutes_spent_teaching_elephant" => self.minutes_spent_teaching_elephant = Parameter::parse(name, value)?,
+            _ => return Err(format!("Unknown parameter: {}", name)),
+        }
+        Ok(())
     }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-struct ValveGraph {
+pub struct ValveGraph {
     ordering: HashMap<String, usize>,
     valves: Vec<Valve>,
     distances: HashMap<(usize, usize), usize>,
@@ -101,30 +143,21 @@ impl ValveGraph {
         self.valves.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.valves.is_empty()
+    }
+
     pub fn compute_distances(valves: Vec<Valve>) -> HashMap<(usize, usize), usize> {
         let n_nodes = valves.len();
-        let edges: Vec<(usize, usize)> = (0..n_nodes).into_iter().permutations(2).map(|v| (v[0], v[1])).collect();
-        let mut distances: HashMap<(usize, usize), usize> = edges.into_iter().map(|(i, j)| {
-                if valves[i].get_neighbors().contains(&valves[j].get_label()) {
-                    ((i, j), 1)
-                } else {
-                    ((i, j), DISTANCE_BIGGER_THAN_MAX)
-                }
-        }).collect();
-        let permutations: Vec<(usize, usize, usize)> = (0..n_nodes).into_iter().permutations(3).map(|v| (v[0], v[1], v[2])).collect();
-        for (k, i, j ) in permutations {
-            // We go through the k's first so that we solve all k = 0, then k = 1, etc. recursively.
-            let old_distance = *distances.get(&(i, j)).unwrap();
-            let candidate = distances.get(&(i, k)).unwrap() + distances.get(&(k, j)).unwrap();
-            let new_distance = if candidate < old_distance {
-                candidate
-            } else {
-                old_distance
-            };
-            distances.insert((i, j), new_distance);
-        }
-        
-        return distances;
+        let indices = (0..n_nodes).collect::<Vec<_>>();
+        let distances = search::floyd_warshall(
+            &indices,
+            |i, j| if valves[*i].get_neighbors().contains(&valves[*j].get_label()) { Some(1) } else { None },
+        );
+        // Valves that can't reach each other are too far apart to ever be worth walking between.
+        indices.iter().cartesian_product(indices.iter())
+            .map(|(i, j)| ((*i, *j), *distances.get(&(*i, *j)).unwrap_or(&DISTANCE_BIGGER_THAN_MAX)))
+            .collect()
     }
 
     pub fn get_maximum_flow(&self, unvisited: &HashSet<usize>, time_remaining: Time, current_position: usize, max_so_far: u32) -> u32 {
@@ -300,15 +333,15 @@ impl ValveGraph {
         self.valves.iter().map(|v| if v.is_open() {v.get_flow_rate()} else { 0 }).sum()
     }
 
-    pub fn get_cumulative_pressure_from(&self, t: usize) -> u32 {
-        self.valves.iter().map(|v| v.get_cumulative_pressure_from(t)).sum()
+    pub fn get_cumulative_pressure_from(&self, t: Time, minutes_until_eruption: Time) -> u32 {
+        self.valves.iter().map(|v| v.get_cumulative_pressure_from(t, minutes_until_eruption)).sum()
     }
 
 }
 
 
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
-enum Plan {
+pub enum Plan {
     Working(usize, Time),
     Done,
 }
@@ -349,7 +382,7 @@ impl Plan {
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
-struct Valve {
+pub struct Valve {
     label: String,
     flow_rate: u32,
     neighbors: Vec<String>,
@@ -379,32 +412,20 @@ impl Valve {
         ).collect()
     }
 
-    pub fn from_strings(strings: Vec<String>) -> Vec<Self> {
-        strings.into_iter().map(Self::from_string).collect()
+    pub fn from_strings(strings: Vec<String>) -> Result<Vec<Self>, ParseError> {
+        InputParser::parse_each_line(strings, Self::from_string)
     }
 
-    pub fn from_string(s: String) -> Self {
-        let halves = s.split(';').collect::<Vec<_>>();
-        assert_eq!(halves.len(), 2);
-
-        let (flow_rate_half, neighbor_half) = (halves[0], halves[1]);
-        let label_and_flow_rate = flow_rate_half.split('=').collect::<Vec<_>>();
-        assert_eq!(label_and_flow_rate.len(), 2);
-
-        let flow_rate = label_and_flow_rate[1].parse::<u32>().unwrap();
-        let label_parts = label_and_flow_rate[0].split_whitespace().collect::<Vec<_>>();
-        assert_eq!(label_parts.len(), 5);
-        let label = label_parts[1];
-
-        let mut neighbors_string = &neighbor_half[" tunnels lead to valve".len()..];
-        if neighbors_string.starts_with('s') {
-            neighbors_string = &neighbors_string[2..];
-        } else {
-            neighbors_string = &neighbors_string[1..];
-        }
-        let neighbors = neighbors_string.split(", ").map(|s| s.to_string()).collect::<Vec<_>>();
-
-        Self::new(label.to_string(), flow_rate, neighbors)
+    pub fn from_string(s: String) -> Result<Self, ParseError> {
+        Parser::parse_all(&s, |parser| {
+            parser.tag("Valve ")?;
+            let label = parser.word()?;
+            parser.tag(" has flow rate=")?;
+            let flow_rate = parser.integer::<u32>()?;
+            parser.one_of(&["; tunnels lead to valves ", "; tunnel leads to valve "])?;
+            let neighbors = parser.sep_by(|p| p.word(), ", ")?;
+            Ok(Self::new(label, flow_rate, neighbors))
+        })
     }
 
     pub fn get_label(&self) -> String {
@@ -419,9 +440,9 @@ impl Valve {
         &self.neighbors
     }
 
-    pub fn get_cumulative_pressure_from(&self, t: Time) -> u32 {
+    pub fn get_cumulative_pressure_from(&self, t: Time, minutes_until_eruption: Time) -> u32 {
         if self.is_open {
-            (INITIAL_MINUTES_UNTIL_ERUPTION - t) as u32 * self.flow_rate
+            minutes_until_eruption.saturating_sub(t) as u32 * self.flow_rate
         } else { 0 }
     }
 
@@ -453,34 +474,34 @@ mod test_problem_16 {
     use super::*;
 
     fn get_example_input() -> Vec<String> {
-        InputParser::new().parse_as_string("example_input_16.txt").unwrap()
+        InputParser::new().parse_as_string("2022/example_input_16.txt").unwrap()
     }
 
     #[test]
     fn test_problem_16a_passes() {
         
-        assert_eq!(solve_problem_16a(get_example_input()), 1651);
+        assert_eq!(solve_problem_16a(get_example_input(), INITIAL_MINUTES_UNTIL_ERUPTION).unwrap(), 1651);
         
-        let input = InputParser::new().parse_as_string("input_16.txt").unwrap();
+        let input = InputParser::new().parse_as_string("2022/input_16.txt").unwrap();
 
-        let answer = solve_problem_16a(input);
+        let answer = solve_problem_16a(input, INITIAL_MINUTES_UNTIL_ERUPTION).unwrap();
         assert_eq!(answer, 2183);
     }
     
     #[test]
     fn test_problem_16b_passes() {
-        assert_eq!(solve_problem_16b(get_example_input()), 1707);
+        assert_eq!(solve_problem_16b(get_example_input(), INITIAL_MINUTES_UNTIL_ERUPTION, MINUTES_SPENT_TEACHING_ELEPHANT).unwrap(), 1707);
 
-        let input = InputParser::new().parse_as_string("input_16.txt").unwrap();
+        let input = InputParser::new().parse_as_string("2022/input_16.txt").unwrap();
 
-        let answer = solve_problem_16b(input);
+        let answer = solve_problem_16b(input, INITIAL_MINUTES_UNTIL_ERUPTION, MINUTES_SPENT_TEACHING_ELEPHANT).unwrap();
         assert_eq!(answer, 0);
     }
 
     #[test]
     fn test_computes_path_with_elephant() {
 
-        let valves = Valve::from_strings(get_example_input());
+        let valves = Valve::from_strings(get_example_input()).unwrap();
 
         let graph = ValveGraph::new(valves.clone());
 
@@ -693,7 +714,7 @@ mod test_problem_16 {
     #[test]
     fn test_valve_graph_initializes() {
         
-        let valves = Valve::from_strings(get_example_input());
+        let valves = Valve::from_strings(get_example_input()).unwrap();
 
         let mut graph = ValveGraph::new(valves.clone());
         assert_eq!(graph.len(), 10);
@@ -755,7 +776,7 @@ mod test_problem_16 {
         // Valve II has flow rate=0; tunnels lead to valves AA, JJ
         // Valve JJ has flow rate=21; tunnel leads to valve II
 
-        let valves = Valve::from_strings(get_example_input());
+        let valves = Valve::from_strings(get_example_input()).unwrap();
         assert_eq!(valves.len(), 10);
 
         assert_eq!(valves[0].get_label(), "AA".to_string());
@@ -774,17 +795,19 @@ mod test_problem_16 {
         assert!(!valve.is_closed());
         assert!(valve.is_open());
 
-        assert_eq!(valve.get_cumulative_pressure_from(30), 0);
+        assert_eq!(valve.get_cumulative_pressure_from(30, INITIAL_MINUTES_UNTIL_ERUPTION), 0);
         
         let mut valve = valves[9].clone();
         valve.open();
 
-        assert_eq!(valve.get_cumulative_pressure_from(30), 0);
-        assert_eq!(valve.get_cumulative_pressure_from(29), 21);
-        assert_eq!(valve.get_cumulative_pressure_from(28), 42);
-        assert_eq!(valve.get_cumulative_pressure_from(1), 29 * 21);
+        assert_eq!(valve.get_cumulative_pressure_from(30, INITIAL_MINUTES_UNTIL_ERUPTION), 0);
+        assert_eq!(valve.get_cumulative_pressure_from(29, INITIAL_MINUTES_UNTIL_ERUPTION), 21);
+        assert_eq!(valve.get_cumulative_pressure_from(28, INITIAL_MINUTES_UNTIL_ERUPTION), 42);
+        assert_eq!(valve.get_cumulative_pressure_from(1, INITIAL_MINUTES_UNTIL_ERUPTION), 29 * 21);
+        assert_eq!(valve.get_cumulative_pressure_from(1, 40), 39 * 21);
+        assert_eq!(valve.get_cumulative_pressure_from(41, 40), 0);
 
-        assert_eq!(valves[2].get_cumulative_pressure_from(0), 0);
+        assert_eq!(valves[2].get_cumulative_pressure_from(0, INITIAL_MINUTES_UNTIL_ERUPTION), 0);
         
     }
     #[test]
@@ -5,40 +5,99 @@ use std::iter::Repeat;
 
 use crate::input::input::InputParser;
 use crate::utility::direction::Direction;
+use crate::utility::grid::Grid;
 use crate::utility::vector::Vec2;
+use crate::problems::solution::{Answer, Parameter, Solution};
+use crate::utility::error::ParseError;
 
-pub fn solve_problem_17a(input: String) -> usize {
+pub fn solve_problem_17a(input: String, width: usize, n_rocks: usize) -> Result<usize, ParseError> {
 
-    let mut simulation = Simulation::from_string(input);
-    simulation.step_until_before_the_nth_rock(2023);
+    let n = n_rocks.checked_add(1).ok_or_else(|| ParseError::Malformed(format!("Can't drop {} rocks.", n_rocks)))?;
+    let mut simulation = Simulation::from_string(input, width)?;
+    simulation.step_until_before_the_nth_rock(n);
 
-    simulation.get_height()
+    Ok(simulation.get_height())
 }
 
-fn solve_problem_17b(input: String) -> usize {
+pub fn solve_problem_17b(input: String, width: usize, target_rock: usize) -> Result<usize, ParseError> {
 
-    let target_rock = 1_000_000_000_000;
-    let mut simulation = Simulation::from_string(input.clone());
-    let (period_start_time, period, height_difference) = simulation.get_period_start_period_and_height_difference().unwrap();
+    // Finding the cycle drops up to this many rocks anyway, so any fewer than that are simply dropped.
+    if target_rock < MAX_PERIOD {
+        return solve_problem_17a(input, width, target_rock);
+    }
+    let mut simulation = Simulation::from_string(input.clone(), width)?;
+    let (period_start_time, period, height_difference) = simulation.get_period_start_period_and_height_difference()
+        .ok_or(ParseError::Malformed(format!("The rocks didn't repeat within {} rocks.", MAX_PERIOD)))?;
     let n_rocks_after_period_start = target_rock - period_start_time;
     let remainder = n_rocks_after_period_start % period;
     let quotient = (n_rocks_after_period_start - remainder) / period;
     let height_from_cycles = quotient * height_difference;
 
-    let mut simulation = Simulation::from_string(input);
+    let mut simulation = Simulation::from_string(input, width)?;
     simulation.step_until_before_the_nth_rock(period_start_time + remainder + 1);
 
-    simulation.get_height() + height_from_cycles
+    Ok(simulation.get_height() + height_from_cycles)
+}
+
+pub struct Problem17 {
+    pub width: usize,
+    pub rocks_a: usize,
+    pub rocks_b: usize,
+}
+
+impl Default for Problem17 {
+    fn default() -> Self {
+        Self { width: DEFAULT_WIDTH, rocks_a: 2022, rocks_b: 1_000_000_000_000 }
+    }
+}
+
+impl Solution for Problem17 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_17a(input, self.width, self.rocks_a)?.into())
+    }
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_17b(input, self.width, self.rocks_b)?.into())
+    }
+
+    fn get_parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::new("width", self.width, "Width of the chamber the rocks fall into."),
+            Parameter::new("rocks_a", self.rocks_a, "Rocks to drop in part a."),
+            Parameter::new("rocks_b", self.rocks_b, "Rocks to drop in part b."),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            // The widest rock is 4 squares across and starts 2 squares from the left wall.
+            "width" => match Parameter::parse(name, value)? {
+                n if n < 6 => return Err("width must be at least 6.".to_string()),
+                n => self.width = n,
+            },
+            "rocks_a" => self.rocks_a = Parameter::parse(name, value)?,
+            "rocks_b" => self.rocks_b = Parameter::parse(name, value)?,
+            _ => return Err(format!("Unknown parameter: {}", name)),
+        }
+        Ok(())
+    }
 }
 
 type Time = usize;
 
-const WIDTH: usize = 7;
+const DEFAULT_WIDTH: usize = 7;
 const MAX_PERIOD: usize = 50455;
 
 #[derive(Debug, PartialEq, Eq)]
-struct Simulation {
+pub struct Simulation {
     jet_patterns: Vec<Direction>,
+    width: usize,
     time_elapsed: Time,
     n_rocks_fallen: usize,
     simulation_phase: SimulationPhase,
@@ -49,10 +108,11 @@ struct Simulation {
 
 impl Simulation {
 
-    pub fn new(jet_patterns: Vec<Direction>) -> Self {
+    pub fn new(jet_patterns: Vec<Direction>, width: usize) -> Self {
         let initial_rock = Rock::Square;
         Self {
             jet_patterns,
+            width,
             time_elapsed: 0,
             n_rocks_fallen: 0,
             simulation_phase: SimulationPhase::NewRock,
@@ -62,16 +122,17 @@ impl Simulation {
         }
     }
 
-    pub fn from_string(jet_patterns: String) -> Self {
-        Self::new(
-            jet_patterns.chars().filter(|c| *c == '<' || *c == '>').map(|c| {
-                match c {
-                    '<' => Direction::Left,
-                    '>' => Direction::Right,
-                    _ => panic!("We filtered down to just these already.")
-                }
-            }).collect()
-        )
+    pub fn from_string(jet_patterns: String, width: usize) -> Result<Self, ParseError> {
+        // Jets only push sideways, and only the arrows stand for them.
+        let jet_patterns = jet_patterns.trim_end().chars().enumerate().map(|(i, c)| {
+            Direction::from_char(c).ok()
+                .filter(|direction| matches!(direction, Direction::Left | Direction::Right) && direction.to_arrow() == c)
+                .ok_or_else(|| ParseError::unexpected_token(1, i + 1, "< or >", &c.to_string()))
+        }).collect::<Result<Vec<_>, ParseError>>()?;
+        if jet_patterns.is_empty() {
+            return Err(ParseError::Malformed("There should be at least one jet pattern.".to_string()));
+        }
+        Ok(Self::new(jet_patterns, width))
     }
 
     pub fn get_time_elapsed(&self) -> Time {
@@ -121,7 +182,7 @@ impl Simulation {
             return true;
         }
         let max_y = self.occupied_squares_on_surface.get_max_y();
-        (0..WIDTH).into_iter().all(|x| self.occupied_squares_on_surface.contains(Vec2::new(x as i32, max_y as i32)))
+        (0..self.width).into_iter().all(|x| self.occupied_squares_on_surface.contains(Vec2::new(x as i32, max_y as i32)))
     }
 
     pub fn get_height(&self) -> usize {
@@ -166,7 +227,7 @@ impl Simulation {
             },
             SimulationPhase::HandleFall(rock_position) => {
                 if self.falling_rock_has_landed() {
-                    self.occupied_squares_on_surface = self.occupied_squares_on_surface.union(&self.falling_rock.get_stone_positions_unchecked(rock_position));
+                    self.occupied_squares_on_surface = self.occupied_squares_on_surface.union(&self.falling_rock.get_stone_positions_unchecked(rock_position, self.width), self.width);
                     self.simulation_phase = SimulationPhase::NewRock;
                 } else {
                     let new_position = self.get_movement_effect(Direction::Down);
@@ -192,18 +253,18 @@ impl Simulation {
         if direction == Direction::Down {
             if original_position.y() == 0 {
                 return original_position;
-            } else if self.falling_rock.get_stone_positions_unchecked(potential_new_position).is_disjoint(&self.occupied_squares_on_surface) {
+            } else if self.falling_rock.get_stone_positions_unchecked(potential_new_position, self.width).is_disjoint(&self.occupied_squares_on_surface) {
                 return potential_new_position;
             } else {
                 return original_position;
             }
         }
         
-        if self.falling_rock.rock_would_collide_with_wall(potential_new_position) {
+        if self.falling_rock.rock_would_collide_with_wall(potential_new_position, self.width) {
             return original_position;
         } 
 
-        let rock_squares = self.falling_rock.get_stone_positions_unchecked(potential_new_position);
+        let rock_squares = self.falling_rock.get_stone_positions_unchecked(potential_new_position, self.width);
 
         if !rock_squares.is_disjoint(&self.occupied_squares_on_surface) {
             return original_position;
@@ -221,7 +282,7 @@ impl Simulation {
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
-enum SimulationPhase {
+pub enum SimulationPhase {
     NewRock,
     HandleJet(Vec2),
     HandleFall(Vec2),
@@ -239,7 +300,7 @@ impl SimulationPhase {
 }
 
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
-enum Rock {
+pub enum Rock {
     Minus,
     Plus,
     BackwardsL,
@@ -279,16 +340,16 @@ impl Rock {
         }
     }
 
-    pub fn rock_would_collide_with_wall(&self, rock_position: Vec2) -> bool {
-        (rock_position.x() < 0) || (rock_position.x() + self.get_width() as i32 > WIDTH as i32)
+    pub fn rock_would_collide_with_wall(&self, rock_position: Vec2, chamber_width: usize) -> bool {
+        (rock_position.x() < 0) || (rock_position.x() + self.get_width() as i32 > chamber_width as i32)
     }
 
-    pub fn get_stone_positions_at_surface(&self, rock_position: Vec2) -> OccupiedSquares {
-        self.get_stone_positions_unchecked(rock_position).get_surface()
+    pub fn get_stone_positions_at_surface(&self, rock_position: Vec2, chamber_width: usize) -> OccupiedSquares {
+        self.get_stone_positions_unchecked(rock_position, chamber_width).get_surface(chamber_width)
     }
 
-    pub fn get_stone_positions_unchecked(&self, rock_position: Vec2) -> OccupiedSquares {
-        assert!(!self.rock_would_collide_with_wall(rock_position));
+    pub fn get_stone_positions_unchecked(&self, rock_position: Vec2, chamber_width: usize) -> OccupiedSquares {
+        assert!(!self.rock_would_collide_with_wall(rock_position, chamber_width));
         self.get_relative_stone_positions().shift(rock_position)
     }
 
@@ -336,17 +397,18 @@ impl Rock {
 
 }
 
+// Only occupied squares are stored, so every cell set in the grid is true.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
-struct OccupiedSquares(HashSet<Vec2>);
+pub struct OccupiedSquares(Grid<bool>);
 
 impl OccupiedSquares {
 
     pub fn new_unchecked(vecs: HashSet<Vec2>) -> Self {
-        Self(vecs)
+        Self(Grid::from_cells(vecs.into_iter().map(|v| (v, true)), false))
     }
 
-    pub fn new(vecs: HashSet<Vec2>) -> Self {
-        Self(vecs).get_surface()
+    pub fn new(vecs: HashSet<Vec2>, width: usize) -> Self {
+        Self::new_unchecked(vecs).get_surface(width)
     }
 
     pub fn len(&self) -> usize {
@@ -357,8 +419,12 @@ impl OccupiedSquares {
         self.0.is_empty()
     }
 
+    pub fn get_squares(&self) -> impl Iterator<Item = Vec2> + '_ {
+        self.0.iter().map(|(v, _)| v)
+    }
+
     pub fn is_disjoint(&self, other: &Self) -> bool {
-        self.intersect(other).is_empty()
+        !self.get_squares().any(|v| other.contains(v))
     }
 
     pub fn intersects(&self, other: &Self) -> bool {
@@ -366,51 +432,51 @@ impl OccupiedSquares {
     }
 
     pub fn contains(&self, v: Vec2) -> bool {
-        self.intersects(&Self::new_unchecked(vec![v].into_iter().collect()))
+        self.0[v]
     }
 
     pub fn intersect(&self, other: &Self) -> Self {
-        Self::new(self.0.intersection(&other.0).cloned().collect())
+        Self::new_unchecked(self.get_squares().filter(|v| other.contains(*v)).collect())
     }
 
-    pub fn union(&self, other: &Self) -> Self {
-        Self::new(self.0.union(&other.0).cloned().collect())
+    pub fn union(&self, other: &Self, width: usize) -> Self {
+        Self::new(self.get_squares().chain(other.get_squares()).collect(), width)
     }
 
     pub fn get_max_y(&self) -> usize {
-        self.0.iter().map(|v| v.y()).max().unwrap_or(0) as usize
+        self.0.get_bounds().map_or(0, |(_, bottom_right)| bottom_right.y() as usize)
     }
 
     pub fn shift(&self, delta_v: Vec2) -> Self {
-        Self::new_unchecked(self.0.iter().map(|v| v.clone() + delta_v).collect())
+        Self::new_unchecked(self.get_squares().map(|v| v + delta_v).collect())
     }
 
     fn get_signature(&self) -> u64 {
         let max_height = self.get_max_y();
-        let mut relative_heights = self.0.iter().map(|v| *v - Vec2::new(0, max_height as i32)).collect::<Vec<Vec2>>();
+        let mut relative_heights = self.get_squares().map(|v| v - Vec2::new(0, max_height as i32)).collect::<Vec<Vec2>>();
         relative_heights.sort_unstable();
         let mut hasher = DefaultHasher::new();
         relative_heights.hash(&mut hasher);
         hasher.finish()
     }
 
-    fn get_surface(&self) -> Self {
+    fn get_surface(&self, width: usize) -> Self {
         let mut to_return = HashSet::new();
         
         let mut y = self.get_max_y() as i32;
         let mut x_found: HashSet<i32> = HashSet::new();
         while y >= 0 {
-            let squares_at_y = self.0.iter().filter(|v| v.y() == y).collect::<HashSet<_>>();
+            let squares_at_y = self.get_squares().filter(|v| v.y() == y).collect::<HashSet<_>>();
             for v in squares_at_y.clone() {
-                let bounded_left = v.x() == 0 || squares_at_y.contains(&(*v - Vec2::i()));
-                let bounded_top = to_return.contains(&(*v + Vec2::j()));
-                let bounded_right = v.x() == (WIDTH as i32 - 1) || squares_at_y.contains(&(*v + Vec2::i()));
+                let bounded_left = v.x() == 0 || squares_at_y.contains(&(v - Vec2::i()));
+                let bounded_top = to_return.contains(&(v + Vec2::j()));
+                let bounded_right = v.x() == (width as i32 - 1) || squares_at_y.contains(&(v + Vec2::i()));
                 if !(bounded_left && bounded_top && bounded_right) {
-                    to_return.insert(*v);
+                    to_return.insert(v);
                     x_found.insert(v.x());
                 }
             }
-            if x_found.len() == WIDTH {
+            if x_found.len() == width {
                 return Self::new_unchecked(to_return);
             }
             y -= 1;
@@ -433,38 +499,59 @@ mod test_problem_17 {
     #[test]
     fn test_problem_17a_passes() {
 
-        assert_eq!(solve_problem_17a(get_example_input()), 3068);
+        assert_eq!(solve_problem_17a(get_example_input(), DEFAULT_WIDTH, 2022).unwrap(), 3068);
         
-        let input = InputParser::new().parse_to_single_string("input_17.txt").unwrap();
+        let input = InputParser::new().parse_to_single_string("2022/input_17.txt").unwrap();
 
-        let answer = solve_problem_17a(input);
+        let answer = solve_problem_17a(input, DEFAULT_WIDTH, 2022).unwrap();
         assert_eq!(answer, 3215);
     }
     
     #[test]
     fn test_problem_17b_passes() {
         
-        assert_eq!(solve_problem_17b(get_example_input()), 1514285714288);
+        assert_eq!(solve_problem_17b(get_example_input(), DEFAULT_WIDTH, 1_000_000_000_000).unwrap(), 1514285714288);
 
-        let input = InputParser::new().parse_to_single_string("input_17.txt").unwrap();
+        let input = InputParser::new().parse_to_single_string("2022/input_17.txt").unwrap();
 
-        let answer = solve_problem_17b(input);
+        let answer = solve_problem_17b(input, DEFAULT_WIDTH, 1_000_000_000_000).unwrap();
         assert_eq!(answer, 1575811209487);
     }
 
+    #[test]
+    fn test_drops_fewer_rocks_than_the_period_start() {
+        assert_eq!(solve_problem_17b(get_example_input(), DEFAULT_WIDTH, 10).unwrap(), 17);
+        assert_eq!(solve_problem_17b(get_example_input(), DEFAULT_WIDTH, 0).unwrap(), 0);
+        assert_eq!(solve_problem_17b(get_example_input(), DEFAULT_WIDTH, 2022).unwrap(), 3068);
+        assert!(solve_problem_17a(get_example_input(), DEFAULT_WIDTH, usize::MAX).is_err());
+
+        let mut problem = Problem17::default();
+        problem.set_parameter("rocks_b", "10").unwrap();
+        assert_eq!(problem.part_b(get_example_input()).unwrap(), Answer::Unsigned(17));
+    }
+
+    #[test]
+    fn test_rejects_jets_that_are_not_arrows() {
+        for jets in ["<<^>", "<<v>", "<<W>", "<<e>"] {
+            let error = Simulation::from_string(jets.to_string(), DEFAULT_WIDTH).unwrap_err().to_string();
+            assert!(error.starts_with("Line 1, column 3:"), "{}", error);
+            assert!(error.contains("< or >"), "{}", error);
+        }
+    }
+
     #[test]
     fn test_detects_cycles() {
 
-        let mut simulation = Simulation::from_string(get_example_input());
+        let mut simulation = Simulation::from_string(get_example_input(), DEFAULT_WIDTH).unwrap();
 
         assert_eq!(simulation.get_period_start_period_and_height_difference(), Some((28, 35, 53)));
     }
 
     #[test]
     fn test_steps_until_before_the_nth_rock() {
-        let mut actual_simulation = Simulation::from_string(get_example_input());
+        let mut actual_simulation = Simulation::from_string(get_example_input(), DEFAULT_WIDTH).unwrap();
 
-        let mut expected_simulation = Simulation::from_string(get_example_input());
+        let mut expected_simulation = Simulation::from_string(get_example_input(), DEFAULT_WIDTH).unwrap();
 
         assert_eq!(actual_simulation, expected_simulation);
 
@@ -475,7 +562,7 @@ mod test_problem_17 {
         expected_simulation.step_until_rock_lands();
         assert_eq!(actual_simulation, expected_simulation);
 
-        let mut actual_simulation = Simulation::from_string(get_example_input());
+        let mut actual_simulation = Simulation::from_string(get_example_input(), DEFAULT_WIDTH).unwrap();
         actual_simulation.step_until_before_the_nth_rock(3);
         expected_simulation.step_until_rock_lands();
         assert_eq!(actual_simulation, expected_simulation);
@@ -486,14 +573,14 @@ mod test_problem_17 {
     #[test]
     fn test_gets_surface_in_simulation() {
 
-        let mut simulation = Simulation::from_string(get_example_input());
+        let mut simulation = Simulation::from_string(get_example_input(), DEFAULT_WIDTH).unwrap();
 
         assert!(simulation.get_occupied_squares_on_surface().is_empty());
 
         simulation.step_until_rock_lands();
         assert_eq!(
             simulation.get_occupied_squares_on_surface().clone(),
-            OccupiedSquares::new(vec![Vec2::new(2, 0), Vec2::new(3, 0), Vec2::new(4, 0), Vec2::new(5, 0)].into_iter().collect())
+            OccupiedSquares::new(vec![Vec2::new(2, 0), Vec2::new(3, 0), Vec2::new(4, 0), Vec2::new(5, 0)].into_iter().collect(), DEFAULT_WIDTH)
         );
 
         simulation.falling_rock = Rock::Minus;
@@ -502,7 +589,8 @@ mod test_problem_17 {
         assert_eq!(
             simulation.get_occupied_squares_on_surface().clone(),
             OccupiedSquares::new(
-                vec![Vec2::new(0, 1), Vec2::new(1, 1), Vec2::new(2, 0), Vec2::new(2, 1), Vec2::new(3, 1), Vec2::new(4, 0), Vec2::new(5, 0)].into_iter().collect()
+                vec![Vec2::new(0, 1), Vec2::new(1, 1), Vec2::new(2, 0), Vec2::new(2, 1), Vec2::new(3, 1), Vec2::new(4, 0), Vec2::new(5, 0)].into_iter().collect(),
+                DEFAULT_WIDTH
             )
         );
         
@@ -511,8 +599,8 @@ mod test_problem_17 {
     #[test]
     fn test_gets_surface_when_saturated() {
         let saturated_squares = (0..7).into_iter()
-            .map(|x| Rock::Bar.get_stone_positions_at_surface(Vec2::new(x, 0)))
-            .reduce(|acc, elt| acc.union(&elt)).unwrap();
+            .map(|x| Rock::Bar.get_stone_positions_at_surface(Vec2::new(x, 0), DEFAULT_WIDTH))
+            .reduce(|acc, elt| acc.union(&elt, DEFAULT_WIDTH)).unwrap();
 
         assert_eq!(saturated_squares, OccupiedSquares::new_unchecked((0..7).into_iter().map(|x| Vec2::new(x, 3)).collect()))
     }
@@ -521,9 +609,9 @@ mod test_problem_17 {
     #[test]
     fn test_gets_non_trivial_surface() {
 
-        let mut squares = Rock::Bar.get_stone_positions_at_surface(Vec2::new(3, 0));
-        squares = squares.union(&Rock::Minus.get_stone_positions_at_surface(Vec2::new(0, 4)));
-        squares = squares.union(&Rock::Minus.get_stone_positions_at_surface(Vec2::new(3, 5)));
+        let mut squares = Rock::Bar.get_stone_positions_at_surface(Vec2::new(3, 0), DEFAULT_WIDTH);
+        squares = squares.union(&Rock::Minus.get_stone_positions_at_surface(Vec2::new(0, 4), DEFAULT_WIDTH), DEFAULT_WIDTH);
+        squares = squares.union(&Rock::Minus.get_stone_positions_at_surface(Vec2::new(3, 5), DEFAULT_WIDTH), DEFAULT_WIDTH);
 
         assert_eq!(squares.len(), 8, "{:?}", squares);
     }
@@ -531,10 +619,10 @@ mod test_problem_17 {
     #[test]
     fn test_gets_surface_at_left_wall() {
         
-        let squares = Rock::Minus.get_relative_stone_positions().get_surface();
+        let squares = Rock::Minus.get_relative_stone_positions().get_surface(DEFAULT_WIDTH);
         assert_eq!(squares, OccupiedSquares::new_unchecked(Rock::Minus.get_relative_stone_positions_as_vecs()));
 
-        let squares = Rock::Plus.get_relative_stone_positions().get_surface();
+        let squares = Rock::Plus.get_relative_stone_positions().get_surface(DEFAULT_WIDTH);
         assert_eq!(squares, OccupiedSquares::new_unchecked(
             Rock::Plus.get_relative_stone_positions_as_vecs()
             .difference(
@@ -542,13 +630,13 @@ mod test_problem_17 {
             ).cloned().collect::<HashSet<_>>()
         ));
 
-        let squares = Rock::BackwardsL.get_relative_stone_positions().get_surface();
+        let squares = Rock::BackwardsL.get_relative_stone_positions().get_surface(DEFAULT_WIDTH);
         assert_eq!(squares, OccupiedSquares::new_unchecked(Rock::BackwardsL.get_relative_stone_positions_as_vecs()));
 
-        let squares = Rock::Bar.get_relative_stone_positions().get_surface();
+        let squares = Rock::Bar.get_relative_stone_positions().get_surface(DEFAULT_WIDTH);
         assert_eq!(squares, OccupiedSquares::new_unchecked(Rock::Bar.get_relative_stone_positions_as_vecs()));
 
-        let squares = Rock::Square.get_relative_stone_positions().get_surface();
+        let squares = Rock::Square.get_relative_stone_positions().get_surface(DEFAULT_WIDTH);
         assert_eq!(squares, OccupiedSquares::new_unchecked(
             Rock::Square.get_relative_stone_positions_as_vecs()
             .difference(
@@ -561,7 +649,7 @@ mod test_problem_17 {
     #[test]
     fn test_gets_surface_in_the_middle() {
         
-        let squares = Rock::Plus.get_stone_positions_at_surface(Vec2::new(3, 0));
+        let squares = Rock::Plus.get_stone_positions_at_surface(Vec2::new(3, 0), DEFAULT_WIDTH);
         assert_eq!(squares, OccupiedSquares::new_unchecked(
             Rock::Plus.get_relative_stone_positions_as_vecs()
             .difference(
@@ -569,7 +657,7 @@ mod test_problem_17 {
             ).cloned().into_iter().map(|v| v + Vec2::new(3, 0)).collect::<HashSet<_>>()
         ));
 
-        let squares = Rock::Square.get_stone_positions_at_surface(Vec2::new(1, 0));
+        let squares = Rock::Square.get_stone_positions_at_surface(Vec2::new(1, 0), DEFAULT_WIDTH);
         assert_eq!(squares, OccupiedSquares::new_unchecked(
             Rock::Square.get_relative_stone_positions_as_vecs().into_iter().map(|v| v + Vec2::new(1, 0)).collect()
         ));
@@ -578,7 +666,7 @@ mod test_problem_17 {
     #[test]
     fn test_gets_surface_at_right_wall() {
         
-        let squares = Rock::Plus.get_stone_positions_at_surface(Vec2::new(4, 0));
+        let squares = Rock::Plus.get_stone_positions_at_surface(Vec2::new(4, 0), DEFAULT_WIDTH);
         assert_eq!(squares, OccupiedSquares::new_unchecked(
             Rock::Plus.get_relative_stone_positions_as_vecs()
             .difference(
@@ -586,7 +674,7 @@ mod test_problem_17 {
             ).cloned().into_iter().map(|v| v + Vec2::new(4, 0)).collect::<HashSet<_>>()
         ));
 
-        let squares = Rock::Square.get_stone_positions_at_surface(Vec2::new(5, 0));
+        let squares = Rock::Square.get_stone_positions_at_surface(Vec2::new(5, 0), DEFAULT_WIDTH);
         assert_eq!(squares, OccupiedSquares::new_unchecked(
             Rock::Square.get_relative_stone_positions_as_vecs()
             .difference(
@@ -598,7 +686,7 @@ mod test_problem_17 {
     #[test]
     fn test_simulates() {
         
-        let mut simulation = Simulation::from_string(get_example_input());
+        let mut simulation = Simulation::from_string(get_example_input(), DEFAULT_WIDTH).unwrap();
 
         assert_eq!(simulation.get_n_rocks_fallen(), 0);
         assert_eq!(simulation.get_falling_rock_type(), None);
@@ -650,7 +738,7 @@ mod test_problem_17 {
         assert_eq!(simulation.get_falling_rock_position(), None);
         assert_eq!(
             simulation.get_occupied_squares_on_surface().clone(),
-            OccupiedSquares::new(vec![Vec2::new(2, 0), Vec2::new(3, 0), Vec2::new(4, 0), Vec2::new(5, 0)].into_iter().collect())
+            OccupiedSquares::new(vec![Vec2::new(2, 0), Vec2::new(3, 0), Vec2::new(4, 0), Vec2::new(5, 0)].into_iter().collect(), DEFAULT_WIDTH)
         );
         assert_eq!(simulation.get_time_elapsed(), 9);
         assert_eq!(simulation.get_falling_rock_type(), None);
@@ -702,7 +790,7 @@ mod test_problem_17 {
     #[test]
     fn test_simulates_eleventh_rock() {
 
-        let mut simulation = Simulation::from_string(get_example_input());
+        let mut simulation = Simulation::from_string(get_example_input(), DEFAULT_WIDTH).unwrap();
         simulation.step_until_before_the_nth_rock(11);
 
         assert_eq!(simulation.get_height(), 17);
@@ -715,7 +803,7 @@ mod test_problem_17 {
     #[test]
     fn test_rock_lands() {
 
-        let mut simulation = Simulation::from_string(get_example_input());
+        let mut simulation = Simulation::from_string(get_example_input(), DEFAULT_WIDTH).unwrap();
 
         simulation.occupied_squares_on_surface = OccupiedSquares::new(vec![
             Vec2::new(3, 0),
@@ -723,7 +811,7 @@ mod test_problem_17 {
             Vec2::new(3, 2),
             Vec2::new(3, 3),
             Vec2::new(3, 4),
-        ].into_iter().collect());
+        ].into_iter().collect(), DEFAULT_WIDTH);
 
         // simulation.simulation_phase = SimulationPhase::HandleFall(Vec2::new(3, 6));
         // assert!(!simulation.falling_rock_has_landed());
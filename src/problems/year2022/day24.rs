@@ -0,0 +1,60 @@
+use crate::input::input::InputParser;
+use crate::problems::solution::{Answer, Solution};
+use crate::utility::error::ParseError;
+
+pub fn solve_problem_24a(input: Vec<String>) -> Result<usize, ParseError> {
+    unimplemented!();
+}
+
+pub fn solve_problem_24b(input: Vec<String>) -> Result<usize, ParseError> {
+    unimplemented!();
+}
+
+#[derive(Default)]
+pub struct Problem24;
+
+impl Solution for Problem24 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(InputParser::split_lines(input))
+    }
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_24a(input)?.into())
+    }
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_24b(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod test_problem_24 {
+
+    use super::*;
+
+    #[test]
+    fn test_problem_24a_passes() {
+        
+        let input = InputParser::new().parse_as_string("2022/input_24.txt").unwrap();
+        let shorted_input = input.iter().take(10).map(|i| i.clone()).collect();
+
+        assert_eq!(solve_problem_24a(shorted_input).unwrap(), 0);
+
+        let answer = solve_problem_24a(input).unwrap();
+        assert_eq!(answer, 0);
+    }
+    
+    #[test]
+    fn test_problem_24b_passes() {
+        let input = InputParser::new().parse_as_string("2022/input_24.txt").unwrap();
+        let shorted_input = input.iter().take(10).map(|i| i.clone()).collect();
+
+        assert_eq!(solve_problem_24b(shorted_input).unwrap(), 0);
+
+        let answer = solve_problem_24b(input).unwrap();
+        assert_eq!(answer, 0);
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,74 @@
+use super::*;
+use crate::problems::solution::Solver;
+
+pub fn get_solver(day: usize) -> Option<Box<dyn Solver>> {
+    match day {
+        1 => Some(Box::new(day01::Problem01)),
+        2 => Some(Box::new(day02::Problem02)),
+        3 => Some(Box::new(day03::Problem03)),
+        4 => Some(Box::new(day04::Problem04)),
+        5 => Some(Box::new(day05::Problem05)),
+        6 => Some(Box::new(day06::Problem06)),
+        7 => Some(Box::new(day07::Problem07)),
+        8 => Some(Box::new(day08::Problem08)),
+        9 => Some(Box::new(day09::Problem09)),
+        10 => Some(Box::new(day10::Problem10::default())),
+        11 => Some(Box::new(day11::Problem11::default())),
+        12 => Some(Box::new(day12::Problem12)),
+        13 => Some(Box::new(day13::Problem13)),
+        14 => Some(Box::new(day14::Problem14)),
+        15 => Some(Box::new(day15::Problem15::default())),
+        16 => Some(Box::new(day16::Problem16::default())),
+        17 => Some(Box::new(day17::Problem17::default())),
+        18 => Some(Box::new(day18::Problem18)),
+        19 => Some(Box::new(day19::Problem19)),
+        20 => Some(Box::new(day20::Problem20)),
+        21 => Some(Box::new(day21::Problem21)),
+        22 => Some(Box::new(day22::Problem22)),
+        23 => Some(Box::new(day23::Problem23)),
+        24 => Some(Box::new(day24::Problem24)),
+        25 => Some(Box::new(day25::Problem25)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test_registry {
+    use crate::problems::solution::{Answer, Part};
+
+    use super::*;
+
+    #[test]
+    fn test_registers_every_day() {
+        assert!((1..=25).all(|day| get_solver(day).is_some()));
+        assert!(get_solver(0).is_none());
+        assert!(get_solver(26).is_none());
+    }
+
+    #[test]
+    fn test_registered_solvers_solve_examples() {
+        let solver = get_solver(6).unwrap();
+        assert_eq!(solver.solve(Part::A, "mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap(), Answer::Unsigned(7));
+
+        let solver = get_solver(2).unwrap();
+        assert_eq!(solver.solve(Part::A, "A Y\nB X\nC Z").unwrap(), Answer::Unsigned(15));
+
+        let solver = get_solver(4).unwrap();
+        assert_eq!(solver.solve(Part::B, "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8").unwrap(), Answer::Unsigned(4));
+    }
+
+    #[test]
+    fn test_registered_solvers_report_bad_input() {
+        let solver = get_solver(4).unwrap();
+        let error = solver.solve(Part::A, "2-4,6-8\n2-3,4-x").unwrap_err();
+        assert!(error.to_string().starts_with("Line 2, column 7:"), "{}", error);
+
+        let solver = get_solver(10).unwrap();
+        let error = solver.solve(Part::A, "noop\naddx 3\nadx -5").unwrap_err();
+        assert_eq!(error.to_string(), "Line 3, column 1: expected \"noop or addx <n>\", but got \"adx -5\"");
+
+        let solver = get_solver(12).unwrap();
+        assert_eq!(solver.solve(Part::A, "Sabqponm\nabcryxxl").unwrap_err().to_string(), "The heightmap has no ending point E.");
+    }
+
+}
@@ -0,0 +1,220 @@
+use std::{fmt, time::Duration};
+
+use crate::input::input::InputParser;
+use crate::input::overrides::ParameterOverrides;
+use crate::input::profile::Profile;
+use crate::input::profiles::ProfileBook;
+use crate::problems::solution::{Part, Solver};
+
+use super::dispatch;
+
+pub const DEFAULT_RUNS: usize = 5;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl Phase {
+
+    pub fn all() -> Vec<Self> {
+        vec![Self::Parse, Self::Solve(Part::A), Self::Solve(Part::B)]
+    }
+
+    fn get_part(&self) -> Option<Part> {
+        match self {
+            Self::Parse => None,
+            Self::Solve(part) => Some(*part),
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Solve(part) => write!(f, "{}", part),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PhaseStats {
+    min: Duration,
+    median: Duration,
+    max: Duration,
+}
+
+impl PhaseStats {
+
+    pub fn from_durations(mut durations: Vec<Duration>) -> Option<Self> {
+        if durations.is_empty() {
+            return None;
+        }
+        durations.sort();
+        Some(Self { min: durations[0], median: durations[durations.len() / 2], max: durations[durations.len() - 1] })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayBenchmark {
+    day: usize,
+    phases: Vec<(Phase, Result<PhaseStats, String>)>,
+}
+
+impl DayBenchmark {
+
+    pub fn failed(day: usize, message: String) -> Self {
+        Self { day, phases: Phase::all().into_iter().map(|phase| (phase, Err(message.clone()))).collect() }
+    }
+}
+
+pub fn benchmark_days(
+    parser: &InputParser, year: usize, days: &[usize], profile: &Profile, book: &ProfileBook, overrides: &ParameterOverrides, n_runs: usize
+) -> Vec<DayBenchmark> {
+    days.iter().map(|day| benchmark_day(parser, year, *day, profile, book, overrides, n_runs)).collect()
+}
+
+pub fn benchmark_day(
+    parser: &InputParser, year: usize, day: usize, profile: &Profile, book: &ProfileBook, overrides: &ParameterOverrides, n_runs: usize
+) -> DayBenchmark {
+    let solver = match dispatch::get_solver(year, day, profile, book, overrides) {
+        Ok(solver) => solver,
+        Err(e) => return DayBenchmark::failed(day, e),
+    };
+    match dispatch::load_input(parser, year, day, profile, None) {
+        Ok(input) => benchmark_solver(day, solver.as_ref(), &input, n_runs),
+        Err(e) => DayBenchmark::failed(day, e),
+    }
+}
+
+pub fn benchmark_solver(day: usize, solver: &dyn Solver, input: &str, n_runs: usize) -> DayBenchmark {
+    let phases = Phase::all().into_iter().map(|phase| {
+        let durations = (0..n_runs).map(
+            |_| dispatch::catch_panics(|| solver.time(phase.get_part(), input).map_err(|e| e.to_string()))
+        ).collect::<Result<Vec<_>, String>>();
+        let stats = durations.and_then(|d| PhaseStats::from_durations(d).ok_or("No runs.".to_string()));
+        (phase, stats)
+    }).collect();
+    DayBenchmark { day, phases }
+}
+
+pub fn format_table(benchmarks: &[DayBenchmark]) -> String {
+    let mut lines = vec![format!("{:>3}  {:<5}  {:>12}  {:>12}  {:>12}", "day", "phase", "min", "median", "max")];
+    for benchmark in benchmarks {
+        for (phase, stats) in benchmark.phases.iter() {
+            lines.push(match stats {
+                Ok(stats) => format!(
+                    "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}",
+                    benchmark.day, phase.to_string(), format_duration(stats.min), format_duration(stats.median), format_duration(stats.max)
+                ),
+                Err(e) => format!("{:>3}  {:<5}  {}", benchmark.day, phase.to_string(), e.lines().next().unwrap_or("")),
+            });
+        }
+    }
+    lines.join("\n")
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
+
+pub fn format_json(benchmarks: &[DayBenchmark], n_runs: usize) -> String {
+    let days = benchmarks.iter().map(|benchmark| {
+        let phases = benchmark.phases.iter().map(|(phase, stats)| match stats {
+            Ok(stats) => format!(
+                "\"{}\": {{\"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                phase, stats.min.as_nanos(), stats.median.as_nanos(), stats.max.as_nanos()
+            ),
+            Err(e) => format!("\"{}\": {{\"error\": \"{}\"}}", phase, escape_json(e)),
+        }).collect::<Vec<_>>();
+        format!("    {{\"day\": {}, \"phases\": {{{}}}}}", benchmark.day, phases.join(", "))
+    }).collect::<Vec<_>>();
+    format!("{{\n  \"runs\": {},\n  \"days\": [\n{}\n  ]\n}}\n", n_runs, days.join(",\n"))
+}
+
+fn escape_json(s: &str) -> String {
+    s.chars().map(|c| match c {
+        '"' => "\\\"".to_string(),
+        '\\' => "\\\\".to_string(),
+        '\n' => "\\n".to_string(),
+        c if c.is_control() => format!("\\u{:04x}", c as u32),
+        c => c.to_string(),
+    }).collect()
+}
+
+#[cfg(test)]
+mod test_bench {
+    use std::{env, fs, process};
+
+    use crate::problems::year2022::day06::Problem06;
+
+    use super::*;
+
+    fn get_example_benchmark() -> DayBenchmark {
+        let stats = PhaseStats::from_durations(vec![Duration::from_micros(3), Duration::from_micros(1), Duration::from_micros(2)]).unwrap();
+        DayBenchmark {
+            day: 18,
+            phases: vec![
+                (Phase::Parse, Ok(stats)),
+                (Phase::Solve(Part::A), Err("panicked: not \"yet\"\nimplemented".to_string())),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_summarizes_durations() {
+        let stats = PhaseStats::from_durations(vec![Duration::from_millis(5), Duration::from_millis(1), Duration::from_millis(3), Duration::from_millis(2)]).unwrap();
+        assert_eq!(stats, PhaseStats { min: Duration::from_millis(1), median: Duration::from_millis(3), max: Duration::from_millis(5) });
+        assert_eq!(PhaseStats::from_durations(Vec::new()), None);
+    }
+
+    #[test]
+    fn test_benchmarks_every_phase() {
+        let benchmark = benchmark_solver(6, &Problem06, "mjqjpqmgbljsphdztnvjfqwrcgsmlb", 3);
+        assert_eq!(benchmark.phases.iter().map(|(phase, _)| *phase).collect::<Vec<_>>(), Phase::all());
+        assert!(benchmark.phases.iter().all(|(_, stats)| stats.is_ok()));
+
+        // Day 18 is a stub, so it parses any input and then panics in both parts.
+        let root = env::temp_dir().join(format!("bench_{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("2022")).unwrap();
+        fs::write(root.join("2022/input_18.txt"), "1\n").unwrap();
+
+        let book = ProfileBook::default();
+        let overrides = ParameterOverrides::default();
+        let benchmark = benchmark_day(&InputParser::new().with_input_root(&root), 2022, 18, &Profile::Real, &book, &overrides, 1);
+        fs::remove_dir_all(root).unwrap();
+        assert!(benchmark.phases[0].1.is_ok());
+        assert!(benchmark.phases[1..].iter().all(|(_, stats)| stats.as_ref().unwrap_err().starts_with("panicked: not implemented")));
+
+        let parser = InputParser::new();
+        assert!(benchmark_day(&parser, 2022, 26, &Profile::Real, &book, &overrides, 1).phases[0].1.as_ref().unwrap_err().contains("no problem for day 26"));
+
+        let benchmark = benchmark_day(&parser, 2022, 7, &Profile::Example, &book, &overrides, 1);
+        assert!(benchmark.phases.iter().all(|(_, stats)| stats.is_ok()));
+
+        // Only day 11 has rounds_b, so day 7 runs as if it weren't given.
+        let overrides = dispatch::load_overrides(None, &[("rounds_b".to_string(), "100".to_string())]).unwrap();
+        let benchmarks = benchmark_days(&parser, 2022, &[7, 11], &Profile::Example, &book, &overrides, 1);
+        assert!(benchmarks.iter().all(|benchmark| benchmark.phases.iter().all(|(_, stats)| stats.is_ok())));
+    }
+
+    #[test]
+    fn test_formats_table() {
+        let table = format_table(&[get_example_benchmark()]);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "day  phase           min        median           max");
+        assert_eq!(lines[1], " 18  parse       0.001ms       0.002ms       0.003ms");
+        assert_eq!(lines[2], " 18  a      panicked: not \"yet\"");
+    }
+
+    #[test]
+    fn test_formats_json() {
+        assert_eq!(
+            format_json(&[get_example_benchmark()], 3),
+            "{\n  \"runs\": 3,\n  \"days\": [\n    {\"day\": 18, \"phases\": {\"parse\": {\"min_ns\": 1000, \"median_ns\": 2000, \"max_ns\": 3000}, \"a\": {\"error\": \"panicked: not \\\"yet\\\"\\nimplemented\"}}}\n  ]\n}\n"
+        );
+    }
+}
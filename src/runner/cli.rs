@@ -0,0 +1,445 @@
+use std::{fs, path::PathBuf};
+
+use crate::input::input::InputParser;
+use crate::input::overrides::ParameterOverrides;
+use crate::input::profile::Profile;
+use crate::input::source::Source;
+use crate::input::store::InputStore;
+use crate::problems::registry;
+use crate::problems::solution::Part;
+
+use super::{bench, dispatch, fetch, run_all, scaffold::Scaffold, verify, watch::Watch};
+
+const USAGE: &str = "Usage:
+    run <day> <part> [--year <year>] [--profile <name>] [--input <path>] [--config <path>] [--param <name>=<value>...] [--watch]
+                                         Solve one part of a day, e.g. `run 14 b`.
+                                         Every command works on the latest year unless given --year.
+                                         Profiles pick the input file and parameters, e.g. `--profile example`.
+                                         Pass `--input -` to read the input from stdin.
+                                         Parameters from a config file or --param override the profile's.
+                                         With --watch, solve again whenever the input, profiles or config change.
+    run --all [--year <year>] [--profile <name>] [--threads <n>] [--config <path>] [--param <name>=<value>...]
+                                         Solve both parts of every day in parallel and summarize the results.
+                                         Each --param goes to the days that have it, and at least one day must.
+    bench [<day>...] [--year <year>] [--profile <name>] [--runs <n>] [--json <path>] [--config <path>] [--param <name>=<value>...]
+                                         Time parsing and both parts of each day, every day by default.
+                                         Prints a table and optionally writes the results as JSON.
+    params <day> [--year <year>] [--profile <name>] [--config <path>] [--param <name>=<value>...]
+                                         List a day's parameters with their values and what they do.
+    fetch [<day>...] [--year <year>]     Download real inputs that aren't saved yet, every day by default.
+                                         Needs AOC_SESSION set to the session cookie of a logged-in browser.
+                                         No other command downloads anything.
+    verify [<day>...] [--year <year>] [--profile <name>] [--profiles <path>] [--unanswered]
+                                         Check answers against input/<year>/profiles.toml, every day by default.
+                                         Checks the real profile and every profile with answers unless one is given.
+                                         With --unanswered, also solve the parts that have no answer to check.
+    scaffold <day> [--year <year>]       Create yearYYYY/dayNN.rs from a template, register it and create empty input
+                                         files, starting the year's module if it's new.
+                                         Refuses to overwrite a day that has more than a stub.
+    help                                 Show this message.";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Command {
+    Run {
+        year: usize, day: usize, part: Part, profile: Profile, input: Option<Source>, config: Option<Source>, params: Vec<(String, String)>, watch: bool
+    },
+    RunAll { year: usize, profile: Profile, threads: usize, config: Option<Source>, params: Vec<(String, String)> },
+    Bench {
+        year: usize, days: Vec<usize>, profile: Profile, runs: usize, json: Option<PathBuf>, config: Option<Source>, params: Vec<(String, String)>
+    },
+    Params { year: usize, day: usize, profile: Profile, config: Option<Source>, params: Vec<(String, String)> },
+    Verify { year: usize, days: Vec<usize>, profile: Option<Profile>, profiles: Option<Source>, unanswered: bool },
+    Fetch { year: usize, days: Vec<usize> },
+    Scaffold { year: usize, day: usize },
+    Help,
+}
+
+impl Command {
+
+    pub fn from_args(args: Vec<String>) -> Result<Self, String> {
+        let mut args = args.into_iter();
+        match args.next().as_deref() {
+            None | Some("help") | Some("--help") | Some("-h") => Ok(Self::Help),
+            Some("run") if args.as_slice().first().map(|arg| arg.as_str()) == Some("--all") => {
+                args.next();
+                let mut year = registry::get_default_year();
+                let mut profile = Profile::default();
+                let mut threads = run_all::get_default_threads();
+                let mut config = None;
+                let mut params = Vec::new();
+                while let Some(flag) = args.next() {
+                    match flag.as_str() {
+                        "--year" | "-y" => year = Self::parse_year(args.next())?,
+                        "--profile" | "-p" => profile = Self::parse_profile(args.next())?,
+                        "--config" => config = Some(Self::parse_config(args.next())?),
+                        "--param" => params.push(Self::parse_param(args.next())?),
+                        "--threads" | "-j" => {
+                            let n = args.next().ok_or("--threads needs a number.".to_string())?;
+                            threads = n.parse::<usize>().ok().filter(|n| *n > 0).ok_or(format!("Unable to parse threads: {}", n))?;
+                        },
+                        _ => return Err(format!("Unexpected argument: {}", flag)),
+                    }
+                }
+                Ok(Self::RunAll { year, profile, threads, config, params })
+            },
+            Some("run") => {
+                let day = Self::parse_day(args.next())?;
+                let part = Part::from_string(&args.next().ok_or("Missing part (a or b).".to_string())?)?;
+                let mut year = registry::get_default_year();
+                let mut profile = Profile::default();
+                let mut input = None;
+                let mut config = None;
+                let mut params = Vec::new();
+                let mut watch = false;
+                while let Some(flag) = args.next() {
+                    match flag.as_str() {
+                        "--year" | "-y" => year = Self::parse_year(args.next())?,
+                        "--profile" | "-p" => profile = Self::parse_profile(args.next())?,
+                        "--config" => config = Some(Self::parse_config(args.next())?),
+                        "--param" => params.push(Self::parse_param(args.next())?),
+                        "--watch" | "-w" => watch = true,
+                        "--input" | "-i" => {
+                            let path = args.next().ok_or("--input needs a path.".to_string())?;
+                            input = Some(Source::from_argument(&path));
+                        },
+                        _ => return Err(format!("Unexpected argument: {}", flag)),
+                    }
+                }
+                Ok(Self::Run { year, day, part, profile, input, config, params, watch })
+            },
+            Some("bench") => {
+                let mut year = registry::get_default_year();
+                let mut days = Vec::new();
+                let mut profile = Profile::default();
+                let mut runs = bench::DEFAULT_RUNS;
+                let mut json = None;
+                let mut config = None;
+                let mut params = Vec::new();
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--year" | "-y" => year = Self::parse_year(args.next())?,
+                        "--profile" | "-p" => profile = Self::parse_profile(args.next())?,
+                        "--config" => config = Some(Self::parse_config(args.next())?),
+                        "--param" => params.push(Self::parse_param(args.next())?),
+                        "--runs" | "-n" => {
+                            let n = args.next().ok_or("--runs needs a number.".to_string())?;
+                            runs = n.parse::<usize>().ok().filter(|n| *n > 0).ok_or(format!("Unable to parse runs: {}", n))?;
+                        },
+                        "--json" => {
+                            let path = args.next().ok_or("--json needs a path.".to_string())?;
+                            json = Some(PathBuf::from(path));
+                        },
+                        _ => days.push(Self::parse_day(Some(arg))?),
+                    }
+                }
+                if days.is_empty() {
+                    days = registry::get_registered_days(year);
+                }
+                Ok(Self::Bench { year, days, profile, runs, json, config, params })
+            },
+            Some("params") => {
+                let day = Self::parse_day(args.next())?;
+                let mut year = registry::get_default_year();
+                let mut profile = Profile::default();
+                let mut config = None;
+                let mut params = Vec::new();
+                while let Some(flag) = args.next() {
+                    match flag.as_str() {
+                        "--year" | "-y" => year = Self::parse_year(args.next())?,
+                        "--profile" | "-p" => profile = Self::parse_profile(args.next())?,
+                        "--config" => config = Some(Self::parse_config(args.next())?),
+                        "--param" => params.push(Self::parse_param(args.next())?),
+                        _ => return Err(format!("Unexpected argument: {}", flag)),
+                    }
+                }
+                Ok(Self::Params { year, day, profile, config, params })
+            },
+            Some("verify") => {
+                let mut year = registry::get_default_year();
+                let mut days = Vec::new();
+                let mut profile = None;
+                let mut profiles = None;
+                let mut unanswered = false;
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--year" | "-y" => year = Self::parse_year(args.next())?,
+                        "--profile" | "-p" => profile = Some(Self::parse_profile(args.next())?),
+                        "--unanswered" => unanswered = true,
+                        "--profiles" => {
+                            let path = args.next().ok_or("--profiles needs a path.".to_string())?;
+                            profiles = Some(Source::from_argument(&path));
+                        },
+                        _ => days.push(Self::parse_day(Some(arg))?),
+                    }
+                }
+                if days.is_empty() {
+                    days = registry::get_registered_days(year);
+                }
+                Ok(Self::Verify { year, days, profile, profiles, unanswered })
+            },
+            Some("fetch") => {
+                let mut year = registry::get_default_year();
+                let mut days = Vec::new();
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--year" | "-y" => year = Self::parse_year(args.next())?,
+                        _ => days.push(Self::parse_day(Some(arg))?),
+                    }
+                }
+                if days.is_empty() {
+                    days = registry::get_registered_days(year);
+                }
+                Ok(Self::Fetch { year, days })
+            },
+            Some("scaffold") => {
+                let day = Self::parse_day(args.next())?;
+                let mut year = registry::get_default_year();
+                while let Some(flag) = args.next() {
+                    match flag.as_str() {
+                        "--year" | "-y" => year = Self::parse_year(args.next())?,
+                        _ => return Err(format!("Unexpected argument: {}", flag)),
+                    }
+                }
+                Ok(Self::Scaffold { year, day })
+            },
+            Some(command) => Err(format!("Unknown command: {}", command)),
+        }
+    }
+
+    fn parse_day(maybe_day: Option<String>) -> Result<usize, String> {
+        let day_string = maybe_day.ok_or("Missing day.".to_string())?;
+        day_string.parse::<usize>().map_err(|_| format!("Unable to parse day: {}", day_string))
+    }
+
+    fn parse_year(maybe_year: Option<String>) -> Result<usize, String> {
+        let year_string = maybe_year.ok_or("--year needs a year.".to_string())?;
+        year_string.parse::<usize>().map_err(|_| format!("Unable to parse year: {}", year_string))
+    }
+
+    fn parse_profile(maybe_profile: Option<String>) -> Result<Profile, String> {
+        Profile::from_string(&maybe_profile.ok_or("--profile needs a name.".to_string())?)
+    }
+
+    fn parse_config(maybe_path: Option<String>) -> Result<Source, String> {
+        Ok(Source::from_argument(&maybe_path.ok_or("--config needs a path.".to_string())?))
+    }
+
+    fn parse_param(maybe_param: Option<String>) -> Result<(String, String), String> {
+        ParameterOverrides::parse_assignment(&maybe_param.ok_or("--param needs a name=value.".to_string())?)
+    }
+
+    pub fn execute(self) -> Result<String, String> {
+        match self {
+            Self::Run { year, day, part, profile, input, config, params, watch: true } => {
+                Watch::new(year, day, part, profile, input, config, params)?.watch()
+            },
+            Self::Run { year, day, part, profile, input, config, params, watch: false } => {
+                let book = dispatch::load_profiles(year, None)?;
+                let overrides = dispatch::load_overrides(config, &params)?;
+                dispatch::check_overrides(year, &[day], &overrides)?;
+                dispatch::solve(year, day, part, &profile, input, &book, &overrides).map(|answer| answer.to_string())
+            },
+            Self::RunAll { year, profile, threads, config, params } => {
+                let book = dispatch::load_profiles(year, None)?;
+                let overrides = dispatch::load_overrides(config, &params)?;
+                let days = registry::get_registered_days(year);
+                dispatch::check_overrides(year, &days, &overrides)?;
+                let outcomes = run_all::run_all(&InputParser::new(), year, &days, &profile, &book, &overrides, threads);
+                Ok(run_all::format_report(&outcomes))
+            },
+            Self::Bench { year, days, profile, runs, json, config, params } => {
+                let book = dispatch::load_profiles(year, None)?;
+                let overrides = dispatch::load_overrides(config, &params)?;
+                dispatch::check_overrides(year, &days, &overrides)?;
+                let benchmarks = bench::benchmark_days(&InputParser::new(), year, &days, &profile, &book, &overrides, runs);
+                if let Some(path) = json {
+                    fs::write(&path, bench::format_json(&benchmarks, runs))
+                        .map_err(|e| format!("Unable to write {}: {}", path.display(), e))?;
+                }
+                Ok(bench::format_table(&benchmarks))
+            },
+            Self::Verify { year, days, profile, profiles, unanswered } => {
+                let book = dispatch::load_profiles(year, profiles)?;
+                let checks = verify::verify(&InputParser::new(), year, &days, profile.as_ref(), &book, unanswered);
+                let report = verify::format_report(&checks);
+                if checks.iter().any(|check| check.is_failure()) {
+                    return Err(report);
+                }
+                Ok(report)
+            },
+            Self::Params { year, day, profile, config, params } => {
+                let book = dispatch::load_profiles(year, None)?;
+                let overrides = dispatch::load_overrides(config, &params)?;
+                dispatch::check_overrides(year, &[day], &overrides)?;
+                dispatch::format_parameters(year, day, &profile, &book, &overrides)
+            },
+            Self::Fetch { year, days } => fetch::fetch(&InputStore::from_env(InputParser::new().get_input_root()), year, &days),
+            Self::Scaffold { year, day } => Scaffold::default().scaffold(year, day).map(|done| done.join("\n")),
+            Self::Help => Ok(USAGE.to_string()),
+        }
+    }
+}
+
+pub fn run(args: Vec<String>) -> Result<String, String> {
+    Command::from_args(args).map_err(|e| format!("{}\n\n{}", e, USAGE))?.execute()
+}
+
+#[cfg(test)]
+mod test_cli {
+    use super::*;
+
+    fn to_args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parses_run_command() {
+        assert_eq!(
+            Command::from_args(to_args("run 14 b")),
+            Ok(Command::Run { year: 2022, day: 14, part: Part::B, profile: Profile::Real, input: None, config: None, params: Vec::new(), watch: false })
+        );
+        assert_eq!(
+            Command::from_args(to_args("run 3 a --input some/path.txt")),
+            Ok(Command::Run { year: 2022, day: 3, part: Part::A, profile: Profile::Real, input: Some(Source::Path(PathBuf::from("some/path.txt"))), config: None, params: Vec::new(), watch: false })
+        );
+        assert_eq!(
+            Command::from_args(to_args("run 3 a --input -")),
+            Ok(Command::Run { year: 2022, day: 3, part: Part::A, profile: Profile::Real, input: Some(Source::Stdin), config: None, params: Vec::new(), watch: false })
+        );
+        assert_eq!(
+            Command::from_args(to_args("run 15 a --profile example")),
+            Ok(Command::Run { year: 2022, day: 15, part: Part::A, profile: Profile::Example, input: None, config: None, params: Vec::new(), watch: false })
+        );
+        assert!(Command::from_args(to_args("run 15 a --profile ../real")).is_err());
+        assert_eq!(
+            Command::from_args(to_args("run 16 a --config variants.toml --param minutes_until_eruption=40")),
+            Ok(Command::Run {
+                year: 2022,
+                day: 16,
+                part: Part::A,
+                profile: Profile::Real,
+                input: None,
+                config: Some(Source::Path(PathBuf::from("variants.toml"))),
+                params: vec![("minutes_until_eruption".to_string(), "40".to_string())],
+                watch: false,
+            })
+        );
+        assert_eq!(
+            Command::from_args(to_args("run 1 a --year 2021")),
+            Ok(Command::Run { year: 2021, day: 1, part: Part::A, profile: Profile::Real, input: None, config: None, params: Vec::new(), watch: false })
+        );
+        assert!(Command::from_args(to_args("run 1 a --year twenty")).is_err());
+        assert_eq!(
+            Command::from_args(to_args("run 14 b --watch")),
+            Ok(Command::Run { year: 2022, day: 14, part: Part::B, profile: Profile::Real, input: None, config: None, params: Vec::new(), watch: true })
+        );
+        assert!(Command::from_args(to_args("run 16 a --param minutes_until_eruption")).is_err());
+        assert!(Command::from_args(to_args("run 16 a --config")).is_err());
+    }
+
+    #[test]
+    fn test_parses_run_all_command() {
+        assert_eq!(
+            Command::from_args(to_args("run --all")),
+            Ok(Command::RunAll { year: 2022, profile: Profile::Real, threads: run_all::get_default_threads(), config: None, params: Vec::new() })
+        );
+        assert_eq!(
+            Command::from_args(to_args("run --all --profile example --threads 2 --param rounds_a=30")),
+            Ok(Command::RunAll {
+                year: 2022,
+                profile: Profile::Example,
+                threads: 2,
+                config: None,
+                params: vec![("rounds_a".to_string(), "30".to_string())],
+            })
+        );
+        assert!(Command::from_args(to_args("run --all --threads 0")).is_err());
+        assert!(Command::from_args(to_args("run --all --input x.txt")).is_err());
+    }
+
+    #[test]
+    fn test_parses_bench_command() {
+        assert_eq!(
+            Command::from_args(to_args("bench")),
+            Ok(Command::Bench { year: 2022, days: (1..=25).collect(), profile: Profile::Real, runs: bench::DEFAULT_RUNS, json: None, config: None, params: Vec::new() })
+        );
+        assert_eq!(
+            Command::from_args(to_args("bench 16 17 --profile example --runs 3 --json bench.json")),
+            Ok(Command::Bench { year: 2022, days: vec![16, 17], profile: Profile::Example, runs: 3, json: Some(PathBuf::from("bench.json")), config: None, params: Vec::new() })
+        );
+        assert!(Command::from_args(to_args("bench --runs 0")).is_err());
+        assert!(Command::from_args(to_args("bench --json")).is_err());
+        assert!(Command::from_args(to_args("bench x")).is_err());
+    }
+
+    #[test]
+    fn test_parses_verify_command() {
+        assert_eq!(Command::from_args(to_args("verify")), Ok(Command::Verify { year: 2022, days: (1..=25).collect(), profile: None, profiles: None, unanswered: false }));
+        assert_eq!(
+            Command::from_args(to_args("verify 3 --profile example --profiles mine.toml")),
+            Ok(Command::Verify { year: 2022, days: vec![3], profile: Some(Profile::Example), profiles: Some(Source::Path(PathBuf::from("mine.toml"))), unanswered: false })
+        );
+        assert_eq!(Command::from_args(to_args("verify -y 2021")), Ok(Command::Verify { year: 2021, days: Vec::new(), profile: None, profiles: None, unanswered: false }));
+        assert_eq!(
+            Command::from_args(to_args("verify 6 --unanswered")),
+            Ok(Command::Verify { year: 2022, days: vec![6], profile: None, profiles: None, unanswered: true })
+        );
+        assert!(Command::from_args(to_args("verify --profiles")).is_err());
+        assert!(Command::from_args(to_args("verify --profile")).is_err());
+    }
+
+    #[test]
+    fn test_verify_passes_over_stub_days() {
+        let report = Command::Verify { year: 2022, days: vec![18, 25], profile: None, profiles: None, unanswered: true }.execute().unwrap();
+        assert_eq!(report.lines().last(), Some("0 passed, 0 failed, 4 unknown, 0 not implemented, 0 errors"));
+    }
+
+    #[test]
+    fn test_parses_fetch_command() {
+        assert_eq!(Command::from_args(to_args("fetch")), Ok(Command::Fetch { year: 2022, days: (1..=25).collect() }));
+        assert_eq!(Command::from_args(to_args("fetch 1 2 --year 2021")), Ok(Command::Fetch { year: 2021, days: vec![1, 2] }));
+        assert!(Command::from_args(to_args("fetch --year")).is_err());
+        assert!(Command::from_args(to_args("fetch x")).is_err());
+    }
+
+    #[test]
+    fn test_parses_params_command() {
+        assert_eq!(
+            Command::from_args(to_args("params 11 --param rounds_a=30 --param rounds_b=40")),
+            Ok(Command::Params {
+                year: 2022,
+                day: 11,
+                profile: Profile::Real,
+                config: None,
+                params: vec![("rounds_a".to_string(), "30".to_string()), ("rounds_b".to_string(), "40".to_string())],
+            })
+        );
+        assert!(Command::from_args(to_args("params")).is_err());
+        assert!(Command::from_args(to_args("params 11 --runs 3")).is_err());
+    }
+
+    #[test]
+    fn test_parses_scaffold_command() {
+        assert_eq!(Command::from_args(to_args("scaffold 18")), Ok(Command::Scaffold { year: 2022, day: 18 }));
+        assert_eq!(Command::from_args(to_args("scaffold 1 --year 2023")), Ok(Command::Scaffold { year: 2023, day: 1 }));
+        assert!(Command::from_args(to_args("scaffold")).is_err());
+        assert!(Command::from_args(to_args("scaffold 18 19")).is_err());
+    }
+
+    #[test]
+    fn test_parses_help_command() {
+        assert_eq!(Command::from_args(Vec::new()), Ok(Command::Help));
+        assert_eq!(Command::from_args(to_args("help")), Ok(Command::Help));
+    }
+
+    #[test]
+    fn test_rejects_bad_arguments() {
+        assert!(Command::from_args(to_args("run")).is_err());
+        assert!(Command::from_args(to_args("run x a")).is_err());
+        assert!(Command::from_args(to_args("run 1 c")).is_err());
+        assert!(Command::from_args(to_args("run 1 a --input")).is_err());
+        assert!(Command::from_args(to_args("run 1 a --verbose")).is_err());
+        assert!(Command::from_args(to_args("jump")).is_err());
+    }
+
+}
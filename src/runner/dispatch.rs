@@ -0,0 +1,258 @@
+use std::{any::Any, cell::Cell, collections::HashSet, panic::{self, AssertUnwindSafe}, sync::Once};
+
+use crate::input::input::InputParser;
+use crate::input::overrides::ParameterOverrides;
+use crate::input::profile::Profile;
+use crate::input::profiles::{self, ProfileBook};
+use crate::input::source::Source;
+use crate::problems::registry;
+use crate::problems::solution::{Answer, Part, Solver};
+use crate::utility::error::ParseError;
+
+// A missing profiles file just means no profile has answers or parameters. Errors name whichever source was read.
+pub fn load_profiles(year: usize, source: Option<Source>) -> Result<ProfileBook, String> {
+    let filename = profiles::get_profiles_filename(year);
+    let name = match &source {
+        None => filename.clone(),
+        Some(Source::Stdin) => "stdin".to_string(),
+        Some(source) => InputParser::new().get_path(source).map_or("Profiles".to_string(), |path| path.display().to_string()),
+    };
+    let contents = match source {
+        Some(source) => InputParser::new().parse_to_single_string(source),
+        None => match InputParser::new().parse_to_single_string(filename.as_str()) {
+            Err(ParseError::Io { .. }) => return Ok(ProfileBook::default()),
+            contents => contents,
+        },
+    }.map_err(|e| e.to_string())?;
+    ProfileBook::from_string(&contents).map_err(|e| format!("{}: {}", name, e))
+}
+
+// The config file is optional, and parameters given as name=value are applied on top of it.
+pub fn load_overrides(config: Option<Source>, params: &[(String, String)]) -> Result<ParameterOverrides, String> {
+    let mut overrides = match config {
+        Some(source) => {
+            let contents = InputParser::new().parse_to_single_string(source).map_err(|e| e.to_string())?;
+            ParameterOverrides::from_string(&contents).map_err(|e| format!("Config: {}", e))?
+        },
+        None => ParameterOverrides::default(),
+    };
+    for (name, value) in params {
+        overrides.insert(name.clone(), value.clone());
+    }
+    Ok(overrides)
+}
+
+// Command line parameters go to every day that's run, so each one only has to belong to one of the days.
+pub fn check_overrides(year: usize, days: &[usize], overrides: &ParameterOverrides) -> Result<(), String> {
+    let names = days.iter()
+        .filter_map(|day| registry::get_solver(year, *day))
+        .flat_map(|solver| solver.get_parameters())
+        .map(|parameter| parameter.get_name().to_string())
+        .collect::<HashSet<_>>();
+    match overrides.get_command_line_parameters().keys().find(|name| !names.contains(*name)) {
+        None => Ok(()),
+        Some(name) if days.len() == 1 => Err(format!("Day {}: Unknown parameter: {}", days[0], name)),
+        Some(name) => Err(format!("None of the days being run has a parameter named {}.", name)),
+    }
+}
+
+// Parameters start at each day's defaults, then take the profile's values, then any overrides. Days skip command line
+// parameters they don't have, which check_overrides reports instead.
+pub fn get_solver(
+    year: usize, day: usize, profile: &Profile, book: &ProfileBook, overrides: &ParameterOverrides
+) -> Result<Box<dyn Solver>, String> {
+    let mut solver = registry::get_solver(year, day).ok_or(format!("There is no problem for day {} of {}.", day, year))?;
+    for (name, value) in book.get_parameters(day, profile) {
+        solver.set_parameter(&name, &value).map_err(|e| format!("Day {} {} profile: {}", day, profile, e))?;
+    }
+    for (name, value) in overrides.get_day_parameters(day) {
+        solver.set_parameter(&name, &value).map_err(|e| format!("Day {}: {}", day, e))?;
+    }
+    let names = solver.get_parameters().iter().map(|parameter| parameter.get_name().to_string()).collect::<HashSet<_>>();
+    for (name, value) in overrides.get_command_line_parameters().iter().filter(|(name, _)| names.contains(*name)) {
+        solver.set_parameter(name, value).map_err(|e| format!("Day {}: {}", day, e))?;
+    }
+    Ok(solver)
+}
+
+// An explicit input overrides the profile's input file, but the profile's parameters still apply.
+pub fn load_input(parser: &InputParser, year: usize, day: usize, profile: &Profile, input: Option<Source>) -> Result<String, String> {
+    let source = input.unwrap_or(Source::Profile { year, day, profile: profile.clone() });
+    parser.parse_to_single_string(source).map_err(|e| e.to_string())
+}
+
+pub fn solve(
+    year: usize, day: usize, part: Part, profile: &Profile, input: Option<Source>, book: &ProfileBook, overrides: &ParameterOverrides
+) -> Result<Answer, String> {
+    let solver = get_solver(year, day, profile, book, overrides)?;
+    let input = load_input(&InputParser::new(), year, day, profile, input)?;
+    solver.solve(part, &input).map_err(|e| e.to_string())
+}
+
+pub fn format_parameters(
+    year: usize, day: usize, profile: &Profile, book: &ProfileBook, overrides: &ParameterOverrides
+) -> Result<String, String> {
+    let parameters = get_solver(year, day, profile, book, overrides)?.get_parameters();
+    if parameters.is_empty() {
+        return Ok(format!("Day {} has no parameters.", day));
+    }
+    let mut lines = vec![format!("{:<32}  {:<6}  {:<14}  description", "name", "type", "value")];
+    for parameter in parameters {
+        lines.push(format!(
+            "{:<32}  {:<6}  {:<14}  {}", parameter.get_name(), parameter.get_kind(), parameter.get_value(), parameter.get_description()
+        ));
+    }
+    Ok(lines.join("\n"))
+}
+
+// Unfinished days panic, so callers that run many days turn panics into errors instead.
+pub fn catch_panics<T>(f: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+    silence_panics(|| panic::catch_unwind(AssertUnwindSafe(f)))
+        .unwrap_or_else(|payload| Err(format!("panicked: {}", get_panic_message(payload))))
+}
+
+thread_local! {
+    static SILENCED: Cell<usize> = const { Cell::new(0) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+// Resets the count even if f panics.
+struct SilenceGuard;
+
+impl Drop for SilenceGuard {
+    fn drop(&mut self) {
+        SILENCED.with(|silenced| silenced.set(silenced.get() - 1));
+    }
+}
+
+// The panic hook is shared by every thread, so it's only swapped once, for a hook that skips panics on threads which
+// are silenced and passes the rest on to the hook it replaced. Threads spawned inside f aren't silenced.
+pub fn silence_panics<T>(f: impl FnOnce() -> T) -> T {
+    INSTALL_HOOK.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if SILENCED.with(|silenced| silenced.get()) == 0 {
+                previous_hook(info);
+            }
+        }));
+    });
+    SILENCED.with(|silenced| silenced.set(silenced.get() + 1));
+    let _guard = SilenceGuard;
+    f()
+}
+
+pub fn get_panic_message(payload: Box<dyn Any + Send>) -> String {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(s), _) => s.to_string(),
+        (_, Some(s)) => s.clone(),
+        _ => "unknown panic".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test_dispatch {
+    use std::path::{Path, PathBuf};
+
+    use super::*;
+
+    fn get_input_path(filename: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("input").join("2022").join(filename)
+    }
+
+    #[test]
+    fn test_solves_from_input_path() {
+        let book = ProfileBook::default();
+        let path = get_input_path("example_input_07.txt");
+        assert_eq!(solve(2022, 7, Part::A, &Profile::Real, Some(path.into()), &book, &ParameterOverrides::default()), Ok(Answer::Unsigned(95437)));
+
+        let path = get_input_path("example_input_10.txt");
+        assert_eq!(solve(2022, 10, Part::B, &Profile::Real, Some(path.clone().into()), &book, &ParameterOverrides::default()).unwrap().to_string().lines().count(), 6);
+
+        assert!(solve(2022, 0, Part::A, &Profile::Real, Some(path.into()), &book, &ParameterOverrides::default()).is_err());
+    }
+
+    #[test]
+    fn test_catches_panics() {
+        assert_eq!(catch_panics(|| Ok(1)), Ok(1));
+        assert_eq!(catch_panics::<usize>(|| Err("bad input".to_string())), Err("bad input".to_string()));
+        assert_eq!(catch_panics::<usize>(|| panic!("not yet")), Err("panicked: not yet".to_string()));
+        assert_eq!(catch_panics::<usize>(|| panic!("{} not yet", 18)), Err("panicked: 18 not yet".to_string()));
+        assert_eq!(catch_panics(|| catch_panics::<usize>(|| panic!("inner")).or(Ok(2))), Ok(2));
+        assert_eq!(SILENCED.with(|silenced| silenced.get()), 0);
+    }
+
+    #[test]
+    fn test_solves_from_text() {
+        let input = Source::text("2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8");
+        let book = ProfileBook::default();
+        assert_eq!(solve(2022, 4, Part::A, &Profile::Real, Some(input.clone()), &book, &ParameterOverrides::default()), Ok(Answer::Unsigned(2)));
+        assert_eq!(solve(2022, 4, Part::B, &Profile::Real, Some(input), &book, &ParameterOverrides::default()), Ok(Answer::Unsigned(4)));
+    }
+
+    #[test]
+    fn test_solves_profiles() {
+        let book = ProfileBook::from_string("[day15.example]\nrow_y = 10\nsearch_space_size = 20\n\n[day04.example]\nrow_y = 10").unwrap();
+        assert_eq!(solve(2022, 15, Part::A, &Profile::Example, None, &book, &ParameterOverrides::default()), Ok(Answer::Unsigned(26)));
+        assert_eq!(solve(2022, 15, Part::B, &Profile::Example, None, &book, &ParameterOverrides::default()), Ok(Answer::Unsigned(56_000_011)));
+        assert_eq!(solve(2022, 4, Part::A, &Profile::Example, None, &book, &ParameterOverrides::default()).unwrap_err(), "Day 4 example profile: Unknown parameter: row_y");
+        assert!(solve(2022, 7, Part::A, &Profile::Named("missing".to_string()), None, &book, &ParameterOverrides::default()).unwrap_err().starts_with("Unable to read"));
+    }
+
+    #[test]
+    fn test_loads_each_years_profiles() {
+        let book = load_profiles(2022, Some(Source::text("[day01.real]\na = 12\n\n[day01.example]\nb = 45"))).unwrap();
+        assert_eq!(book.get_answer(1, Part::A, &Profile::Real), Some("12"));
+        assert_eq!(book.get_answer(1, Part::B, &Profile::Example), Some("45"));
+        assert_eq!(book.get_answer(1, Part::B, &Profile::Real), None);
+        assert_eq!(load_profiles(2021, None), Ok(ProfileBook::default()));
+        assert!(load_profiles(2022, Some(Source::text("[day01]"))).unwrap_err().starts_with("Profiles: Line 1"));
+
+        let path = std::env::temp_dir().join(format!("profiles_{}.toml", std::process::id()));
+        std::fs::write(&path, "[day01]").unwrap();
+        let error = load_profiles(2022, Some(path.clone().into())).unwrap_err();
+        assert!(error.starts_with(&format!("{}: Line 1", path.display())), "{}", error);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_overrides_win_over_profiles() {
+        let book = ProfileBook::from_string("[day15.example]\nrow_y = 10\nsearch_space_size = 20").unwrap();
+        let overrides = load_overrides(None, &[("row_y".to_string(), "9".to_string())]).unwrap();
+        assert_eq!(solve(2022, 15, Part::A, &Profile::Example, None, &book, &overrides), Ok(Answer::Unsigned(25)));
+
+        let overrides = load_overrides(Some(Source::text("[day15]\nrow_y = 11")), &[]).unwrap();
+        assert_eq!(solve(2022, 15, Part::A, &Profile::Example, None, &book, &overrides), Ok(Answer::Unsigned(28)));
+
+        let overrides = load_overrides(None, &[("row_y".to_string(), "ten".to_string())]).unwrap();
+        assert_eq!(solve(2022, 15, Part::A, &Profile::Example, None, &book, &overrides).unwrap_err(), "Day 15: Unable to parse row_y as i64: ten");
+    }
+
+    #[test]
+    fn test_formats_parameters() {
+        let book = ProfileBook::default();
+        let overrides = load_overrides(None, &[("rounds_b".to_string(), "40".to_string())]).unwrap();
+        let table = format_parameters(2022, 11, &Profile::Real, &book, &overrides).unwrap();
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[1].starts_with("rounds_a                          usize   20"), "{}", lines[1]);
+        assert!(lines[2].starts_with("rounds_b                          usize   40"), "{}", lines[2]);
+        assert!(lines[3].starts_with("relief                            u32     3"), "{}", lines[3]);
+        assert_eq!(format_parameters(2022, 1, &Profile::Real, &book, &overrides), Ok("Day 1 has no parameters.".to_string()));
+    }
+
+    #[test]
+    fn test_checks_command_line_parameters_belong_to_a_day() {
+        let overrides = load_overrides(None, &[("rounds_a".to_string(), "30".to_string())]).unwrap();
+        assert_eq!(check_overrides(2022, &[6, 11, 26], &overrides), Ok(()));
+        assert_eq!(check_overrides(2022, &[11], &overrides), Ok(()));
+        assert_eq!(check_overrides(2022, &[6], &overrides).unwrap_err(), "Day 6: Unknown parameter: rounds_a");
+        assert_eq!(check_overrides(2022, &[6, 7], &overrides).unwrap_err(), "None of the days being run has a parameter named rounds_a.");
+
+        // Parameters from the config file belong to a day already, so that day still has to have them.
+        let overrides = load_overrides(Some(Source::text("[day06]\nrounds_a = 30")), &[]).unwrap();
+        assert_eq!(check_overrides(2022, &[6], &overrides), Ok(()));
+        assert_eq!(get_solver(2022, 6, &Profile::Real, &ProfileBook::default(), &overrides).err().unwrap(), "Day 6: Unknown parameter: rounds_a");
+    }
+
+}
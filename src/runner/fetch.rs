@@ -0,0 +1,65 @@
+use crate::input::store::{InputStore, SESSION_ENV_VAR};
+
+// Downloads each day's real input unless it's cached already. Nothing else downloads inputs, so running, checking or
+// testing days never reaches the network.
+pub fn fetch(store: &InputStore, year: usize, days: &[usize]) -> Result<String, String> {
+    if !store.has_fetcher() {
+        return Err(format!("Set {} to your session cookie to download inputs.", SESSION_ENV_VAR));
+    }
+    let mut lines = Vec::new();
+    let mut n_failed = 0;
+    for day in days {
+        let path = store.get_path(year, *day);
+        if store.is_cached(year, *day) {
+            lines.push(format!("kept {}", path.display()));
+            continue;
+        }
+        match store.fetch(year, *day) {
+            Ok(_) => lines.push(format!("fetched {}", path.display())),
+            Err(e) => {
+                n_failed += 1;
+                lines.push(e.to_string());
+            },
+        }
+    }
+    if n_failed > 0 {
+        return Err(lines.join("\n"));
+    }
+    Ok(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod test_fetch {
+    use std::{env, fs, process, sync::Arc};
+
+    use crate::input::store::DirectoryFetcher;
+
+    use super::*;
+
+    #[test]
+    fn test_fetches_missing_inputs() {
+        let root = env::temp_dir().join(format!("fetch_{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("remote/2022")).unwrap();
+        fs::create_dir_all(root.join("cache/2022")).unwrap();
+        fs::write(root.join("remote/2022/input_01.txt"), "1\n").unwrap();
+        fs::write(root.join("remote/2022/input_02.txt"), "2\n").unwrap();
+        fs::write(root.join("cache/2022/input_01.txt"), "cached\n").unwrap();
+        fs::write(root.join("cache/2022/input_02.txt"), "").unwrap();
+
+        assert!(fetch(&InputStore::new(root.join("cache")), 2022, &[1]).unwrap_err().contains(SESSION_ENV_VAR));
+
+        let store = InputStore::new(root.join("cache")).with_fetcher(Arc::new(DirectoryFetcher::new(root.join("remote"))));
+        let report = fetch(&store, 2022, &[1, 2]).unwrap();
+        let lines = report.lines().collect::<Vec<_>>();
+        assert!(lines[0].starts_with("kept "), "{}", lines[0]);
+        assert!(lines[1].starts_with("fetched "), "{}", lines[1]);
+        assert_eq!(fs::read_to_string(root.join("cache/2022/input_01.txt")).unwrap(), "cached\n");
+        assert_eq!(fs::read_to_string(root.join("cache/2022/input_02.txt")).unwrap(), "2\n");
+
+        let error = fetch(&store, 2022, &[3]).unwrap_err();
+        assert!(error.starts_with("Unable to fetch the input for day 3 of 2022"), "{}", error);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,8 @@
+pub mod bench;
+pub mod cli;
+pub mod dispatch;
+pub mod fetch;
+pub mod run_all;
+pub mod scaffold;
+pub mod verify;
+pub mod watch;
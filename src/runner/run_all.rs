@@ -0,0 +1,201 @@
+use std::{fmt, panic::{self, AssertUnwindSafe}, sync::Mutex, thread, time::{Duration, Instant}};
+
+use crate::input::input::InputParser;
+use crate::input::overrides::ParameterOverrides;
+use crate::input::profile::Profile;
+use crate::input::profiles::ProfileBook;
+use crate::problems::solution::Part;
+
+use super::{bench, dispatch, verify};
+
+const ANSWER_WIDTH: usize = 20;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Ok(String),
+    Error(String),
+    Panicked(String),
+    NotImplemented,
+}
+
+impl Status {
+
+    // unimplemented!() panics with "not implemented", followed by the message if it was given one.
+    pub fn from_panic_message(message: String) -> Self {
+        if message.starts_with("not implemented") {
+            return Self::NotImplemented;
+        }
+        Self::Panicked(message)
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Ok(_) => write!(f, "ok"),
+            Self::Error(e) => write!(f, "error: {}", verify::first_line(e)),
+            Self::Panicked(message) => write!(f, "panicked: {}", verify::first_line(message)),
+            Self::NotImplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Outcome {
+    day: usize,
+    part: Part,
+    status: Status,
+    time: Duration,
+}
+
+impl Outcome {
+
+    pub fn get_status(&self) -> &Status {
+        &self.status
+    }
+}
+
+pub fn get_default_threads() -> usize {
+    thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+// Solves both parts of every day, with each part as a separate job for a pool of n_threads workers.
+pub fn run_all(
+    parser: &InputParser, year: usize, days: &[usize], profile: &Profile, book: &ProfileBook, overrides: &ParameterOverrides, n_threads: usize
+) -> Vec<Outcome> {
+    let jobs = days.iter().flat_map(|day| [(*day, Part::A), (*day, Part::B)]).collect::<Vec<_>>();
+    let jobs = Mutex::new(jobs.into_iter());
+    let outcomes = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..n_threads.max(1) {
+            scope.spawn(|| dispatch::silence_panics(|| loop {
+                let job = jobs.lock().unwrap().next();
+                let (day, part) = match job {
+                    Some(job) => job,
+                    None => return,
+                };
+                let outcome = run_one(parser, year, day, part, profile, book, overrides);
+                outcomes.lock().unwrap().push(outcome);
+            }));
+        }
+    });
+    let mut outcomes = outcomes.into_inner().unwrap();
+    outcomes.sort_by_key(|outcome| (outcome.day, outcome.part));
+    outcomes
+}
+
+// Callers silence panics first, since this only catches them.
+pub fn run_one(
+    parser: &InputParser, year: usize, day: usize, part: Part, profile: &Profile, book: &ProfileBook, overrides: &ParameterOverrides
+) -> Outcome {
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let solver = dispatch::get_solver(year, day, profile, book, overrides).map_err(Status::Error)?;
+        let input = dispatch::load_input(parser, year, day, profile, None).map_err(Status::Error)?;
+        solver.solve(part, &input).map(|answer| answer.to_string()).map_err(|e| Status::Error(e.to_string()))
+    }));
+    let time = start.elapsed();
+    let status = match result {
+        Ok(Ok(answer)) => Status::Ok(answer),
+        Ok(Err(status)) => status,
+        Err(payload) => Status::from_panic_message(dispatch::get_panic_message(payload)),
+    };
+    Outcome { day, part, status, time }
+}
+
+fn fit(s: &str, width: usize) -> String {
+    if s.chars().count() <= width {
+        return s.to_string();
+    }
+    format!("{}...", s.chars().take(width - 3).collect::<String>())
+}
+
+pub fn format_report(outcomes: &[Outcome]) -> String {
+    let mut lines = vec![format!("{:>3}  {:<4}  {:<20}  {:>12}  status", "day", "part", "answer", "time")];
+    for outcome in outcomes {
+        let answer = match &outcome.status {
+            Status::Ok(answer) => fit(&verify::first_line(answer), ANSWER_WIDTH),
+            _ => String::new(),
+        };
+        lines.push(format!(
+            "{:>3}  {:<4}  {:<20}  {:>12}  {}",
+            outcome.day, outcome.part.to_string(), answer, bench::format_duration(outcome.time), outcome.status
+        ));
+    }
+    let count = |f: &dyn Fn(&Status) -> bool| outcomes.iter().filter(|outcome| f(&outcome.status)).count();
+    lines.push(format!(
+        "{} ok, {} errors, {} panicked, {} not implemented",
+        count(&|s| matches!(s, Status::Ok(_))),
+        count(&|s| matches!(s, Status::Error(_))),
+        count(&|s| matches!(s, Status::Panicked(_))),
+        count(&|s| *s == Status::NotImplemented),
+    ));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test_run_all {
+    use std::{env, fs, process};
+
+    use super::*;
+
+    #[test]
+    fn test_classifies_panics() {
+        assert_eq!(Status::from_panic_message("not implemented".to_string()), Status::NotImplemented);
+        assert_eq!(Status::from_panic_message("not implemented: day 18".to_string()), Status::NotImplemented);
+        assert_eq!(Status::from_panic_message("index out of bounds".to_string()), Status::Panicked("index out of bounds".to_string()));
+    }
+
+    #[test]
+    fn test_runs_days_in_parallel() {
+        // Day 18 is a stub, so any input reaches its unimplemented!().
+        let root = env::temp_dir().join(format!("run_all_{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("2022")).unwrap();
+        fs::copy(InputParser::new().get_input_root().join("2022/input_06.txt"), root.join("2022/input_06.txt")).unwrap();
+        fs::write(root.join("2022/input_18.txt"), "1\n").unwrap();
+
+        let book = ProfileBook::default();
+        let overrides = ParameterOverrides::default();
+        let outcomes = run_all(&InputParser::new().with_input_root(&root), 2022, &[18, 6, 26], &Profile::Real, &book, &overrides, 3);
+        fs::remove_dir_all(root).unwrap();
+
+        let summary = outcomes.iter().map(|outcome| (outcome.day, outcome.part, outcome.status.clone())).collect::<Vec<_>>();
+        assert_eq!(summary[0], (6, Part::A, Status::Ok("1647".to_string())));
+        assert_eq!(summary[1], (6, Part::B, Status::Ok("2447".to_string())));
+        assert_eq!(summary[2], (18, Part::A, Status::NotImplemented));
+        assert_eq!(summary[3], (18, Part::B, Status::NotImplemented));
+        assert_eq!(summary[4], (26, Part::A, Status::Error("There is no problem for day 26 of 2022.".to_string())));
+
+        let report = format_report(&outcomes);
+        let lines = report.lines().collect::<Vec<_>>();
+        assert!(lines[1].starts_with("  6  a     1647"), "{}", lines[1]);
+        assert!(lines[3].ends_with("  not implemented"), "{}", lines[3]);
+        assert!(lines[5].ends_with("  error: There is no problem for day 26 of 2022."), "{}", lines[5]);
+        assert_eq!(lines[7], "2 ok, 2 errors, 0 panicked, 2 not implemented");
+    }
+
+    #[test]
+    fn test_only_gives_command_line_parameters_to_days_that_have_them() {
+        let overrides = dispatch::load_overrides(None, &[("rounds_a".to_string(), "30".to_string())]).unwrap();
+        let outcomes = run_all(&InputParser::new(), 2022, &[6, 11], &Profile::Real, &ProfileBook::default(), &overrides, 2);
+
+        let summary = outcomes.iter().map(|outcome| (outcome.day, outcome.part, outcome.status.clone())).collect::<Vec<_>>();
+        assert_eq!(summary[0], (6, Part::A, Status::Ok("1647".to_string())));
+        assert_eq!(summary[2], (11, Part::A, Status::Ok("149100".to_string())));
+    }
+
+    #[test]
+    fn test_fits_answers_to_the_table() {
+        assert_eq!(fit("1647", ANSWER_WIDTH), "1647");
+        assert_eq!(fit("##..##..##..##..##..##..##..", ANSWER_WIDTH), "##..##..##..##..#...");
+    }
+
+    #[test]
+    fn test_reports_missing_input_for_finished_days() {
+        let outcome = dispatch::silence_panics(
+            || run_one(&InputParser::new(), 2022, 7, Part::A, &Profile::Named("missing".to_string()), &ProfileBook::default(), &ParameterOverrides::default())
+        );
+        assert!(matches!(outcome.status, Status::Error(e) if e.starts_with("Unable to read")));
+    }
+}
@@ -0,0 +1,353 @@
+use std::{fs, path::{Path, PathBuf}};
+
+use crate::input::input::InputParser;
+use crate::input::profile::Profile;
+
+// Every YYYY is replaced with the year, every NN with the zero-padded day and every DD with the day as a plain number.
+const TEMPLATE: &str = "use crate::input::input::InputParser;
+use crate::problems::solution::{Answer, Solution};
+use crate::utility::error::ParseError;
+
+pub fn solve_problem_NNa(_input: Vec<String>) -> Result<usize, ParseError> {
+    unimplemented!();
+}
+
+pub fn solve_problem_NNb(_input: Vec<String>) -> Result<usize, ParseError> {
+    unimplemented!();
+}
+
+#[derive(Default)]
+pub struct ProblemNN;
+
+impl Solution for ProblemNN {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(InputParser::split_lines(input))
+    }
+
+    fn part_a(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_NNa(input)?.into())
+    }
+
+    fn part_b(&self, input: Self::Input) -> Result<Answer, ParseError> {
+        Ok(solve_problem_NNb(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod test_problem_NN {
+
+    use crate::input::{profile::Profile, source::Source};
+    use crate::problems::solution::Part;
+    use crate::runner::dispatch;
+
+    use super::*;
+
+    fn get_input(profile: Profile) -> Vec<String> {
+        InputParser::new().parse_as_string(Source::Profile { year: YYYY, day: DD, profile }).unwrap()
+    }
+
+    // Only profiles with an answer in profiles.toml are checked, so the tests pass until the answers are added.
+    fn get_answer(part: Part, profile: &Profile) -> Option<String> {
+        dispatch::load_profiles(YYYY, None).unwrap().get_answer(DD, part, profile).map(|answer| answer.to_string())
+    }
+
+    #[test]
+    fn test_problem_NNa_passes() {
+        for profile in [Profile::Example, Profile::Real] {
+            if let Some(answer) = get_answer(Part::A, &profile) {
+                assert_eq!(solve_problem_NNa(get_input(profile)).unwrap().to_string(), answer);
+            }
+        }
+    }
+
+    #[test]
+    fn test_problem_NNb_passes() {
+        for profile in [Profile::Example, Profile::Real] {
+            if let Some(answer) = get_answer(Part::B, &profile) {
+                assert_eq!(solve_problem_NNb(get_input(profile)).unwrap().to_string(), answer);
+            }
+        }
+    }
+
+}
+";
+
+pub fn render_template(year: usize, day: usize) -> String {
+    TEMPLATE.replace("YYYY", &year.to_string()).replace("NN", &format!("{:02}", day)).replace("DD", &day.to_string())
+}
+
+// The first day of a new year starts its module and registry off.
+fn render_year_module(day: usize) -> String {
+    format!("pub mod day{:02};\n\npub mod registry;\n", day)
+}
+
+fn render_year_registry(day: usize) -> String {
+    format!(
+        "use super::*;\nuse crate::problems::solution::Solver;\n\npub fn get_solver(day: usize) -> Option<Box<dyn Solver>> {{\n    match day {{\n{}\n        _ => None,\n    }}\n}}\n",
+        get_solver_line(day)
+    )
+}
+
+// A day is still a stub, and safe to replace, while both of its parts are just unimplemented!().
+pub fn is_stub(contents: &str, day: usize) -> bool {
+    ['a', 'b'].iter().all(|part| {
+        let signature = format!("fn solve_problem_{:02}{}(", day, part);
+        let body = contents.find(&signature)
+            .and_then(|i| contents[i..].find('{').map(|j| i + j + 1))
+            .and_then(|start| contents[start..].find("\n}").map(|end| &contents[start..start + end]));
+        body.map(|body| body.trim() == "unimplemented!();").unwrap_or(false)
+    })
+}
+
+pub struct Scaffold {
+    source_root: PathBuf,
+    input_root: PathBuf,
+}
+
+impl Default for Scaffold {
+    fn default() -> Self {
+        Self::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("src"), InputParser::new().get_input_root())
+    }
+}
+
+impl Scaffold {
+
+    pub fn new(source_root: PathBuf, input_root: PathBuf) -> Self {
+        Self { source_root, input_root }
+    }
+
+    // Returns what was done, one line per file.
+    pub fn scaffold(&self, year: usize, day: usize) -> Result<Vec<String>, String> {
+        if !(1..=25).contains(&day) {
+            return Err(format!("There is no day {}; days run from 1 to 25.", day));
+        }
+        let problems = self.source_root.join("problems");
+        let year_root = problems.join(format!("year{}", year));
+        let module_path = year_root.join(format!("day{:02}.rs", day));
+        if let Ok(contents) = fs::read_to_string(&module_path) {
+            if !is_stub(&contents, day) {
+                return Err(format!("{} already has code for day {} of {}, so it won't be overwritten.", module_path.display(), day, year));
+            }
+        }
+
+        let mut done = Vec::new();
+        if !year_root.exists() {
+            create_dir(&year_root)?;
+            write(&year_root.join("mod.rs"), &render_year_module(day))?;
+            write(&year_root.join("registry.rs"), &render_year_registry(day))?;
+            done.push(format!("started {} in {}", year, year_root.display()));
+            for (path, register) in [
+                (problems.join("mod.rs"), register_year_module as fn(&str, usize) -> Option<String>),
+                (problems.join("registry.rs"), register_year),
+            ] {
+                done.push(register_in_file(&path, register, year, &year.to_string())?);
+            }
+        }
+        write(&module_path, &render_template(year, day))?;
+        done.push(format!("wrote {}", module_path.display()));
+
+        for (path, register) in [
+            (year_root.join("mod.rs"), register_module as fn(&str, usize) -> Option<String>),
+            (year_root.join("registry.rs"), register_solver),
+        ] {
+            done.push(register_in_file(&path, register, day, &format!("day {}", day))?);
+        }
+
+        let input_root = self.input_root.join(year.to_string());
+        create_dir(&input_root)?;
+        for profile in [Profile::Example, Profile::Real] {
+            let path = input_root.join(profile.get_input_filename(day));
+            if path.exists() {
+                done.push(format!("kept {}", path.display()));
+            } else {
+                write(&path, "")?;
+                done.push(format!("created {}", path.display()));
+            }
+        }
+        Ok(done)
+    }
+}
+
+fn register_in_file(path: &Path, register: fn(&str, usize) -> Option<String>, key: usize, name: &str) -> Result<String, String> {
+    let contents = fs::read_to_string(path).map_err(|e| format!("Unable to read {}: {}", path.display(), e))?;
+    match register(&contents, key) {
+        Some(contents) => {
+            write(path, &contents)?;
+            Ok(format!("registered {} in {}", name, path.display()))
+        },
+        None => Ok(format!("{} is already registered in {}", name, path.display())),
+    }
+}
+
+fn create_dir(path: &Path) -> Result<(), String> {
+    fs::create_dir_all(path).map_err(|e| format!("Unable to create {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("Unable to write {}: {}", path.display(), e))
+}
+
+fn get_solver_line(day: usize) -> String {
+    format!("        {} => Some(Box::new(day{:02}::Problem{:02})),", day, day, day)
+}
+
+pub fn register_module(contents: &str, day: usize) -> Option<String> {
+    let get_day = |line: &str| line.trim().strip_prefix("pub mod day")?.strip_suffix(';')?.parse::<usize>().ok();
+    insert_in_order(contents, day, &get_day, format!("pub mod day{:02};", day))
+}
+
+pub fn register_solver(contents: &str, day: usize) -> Option<String> {
+    let get_day = |line: &str| line.trim().split_once(" => Some(Box::new(day")?.0.parse::<usize>().ok();
+    insert_in_order(contents, day, &get_day, get_solver_line(day))
+}
+
+pub fn register_year_module(contents: &str, year: usize) -> Option<String> {
+    let get_year = |line: &str| line.trim().strip_prefix("pub mod year")?.strip_suffix(';')?.parse::<usize>().ok();
+    insert_in_order(contents, year, &get_year, format!("pub mod year{};", year))
+}
+
+// Years are listed in YEARS as well as matched in get_solver.
+pub fn register_year(contents: &str, year: usize) -> Option<String> {
+    let get_listed_year = |line: &str| line.trim().strip_suffix(',')?.parse::<usize>().ok();
+    let get_year = |line: &str| line.trim().split_once(" => year")?.0.parse::<usize>().ok();
+    let contents = insert_in_order(contents, year, &get_listed_year, format!("    {},", year))?;
+    insert_in_order(&contents, year, &get_year, format!("        {} => year{}::registry::get_solver(day),", year, year))
+}
+
+// Inserts the line among the lines that get_key recognizes, keeping them sorted by key. Returns None if the key is
+// already there.
+fn insert_in_order(contents: &str, key: usize, get_key: &dyn Fn(&str) -> Option<usize>, line: String) -> Option<String> {
+    let mut lines = contents.lines().map(|l| l.to_string()).collect::<Vec<_>>();
+    let keys = lines.iter().enumerate().filter_map(|(i, l)| get_key(l).map(|k| (i, k))).collect::<Vec<_>>();
+    if keys.iter().any(|(_, k)| *k == key) {
+        return None;
+    }
+    let index = match keys.iter().rev().find(|(_, k)| *k < key) {
+        Some((i, _)) => i + 1,
+        None => keys.first().map(|(i, _)| *i)?,
+    };
+    lines.insert(index, line);
+    let mut to_return = lines.join("\n");
+    if contents.ends_with('\n') {
+        to_return.push('\n');
+    }
+    Some(to_return)
+}
+
+#[cfg(test)]
+mod test_scaffold {
+    use std::{env, process};
+
+    use super::*;
+
+    fn make_scratch_crate(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("scaffold_{}_{}", name, process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/problems/year2022")).unwrap();
+        fs::create_dir_all(root.join("input")).unwrap();
+        fs::write(root.join("src/problems/mod.rs"), "pub mod year2022;\n\npub mod registry;\n").unwrap();
+        fs::write(
+            root.join("src/problems/registry.rs"),
+            "const YEARS: &[usize] = &[\n    2022,\n];\n\n    match year {\n        2022 => year2022::registry::get_solver(day),\n        _ => None,\n    }\n"
+        ).unwrap();
+        fs::write(root.join("src/problems/year2022/mod.rs"), "pub mod day01;\npub mod day03;\n\npub mod registry;\n").unwrap();
+        fs::write(
+            root.join("src/problems/year2022/registry.rs"),
+            "    match day {\n        1 => Some(Box::new(day01::Problem01)),\n        3 => Some(Box::new(day03::Problem03)),\n        _ => None,\n    }\n"
+        ).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_template_is_a_stub() {
+        let rendered = render_template(2022, 18);
+        assert!(rendered.contains("pub struct Problem18;"));
+        assert!(rendered.contains("Source::Profile { year: 2022, day: 18, profile }"));
+        assert!(rendered.contains("load_profiles(2022, None).unwrap().get_answer(18, part, profile)"));
+        assert!(render_template(2022, 5).contains("fn test_problem_05a_passes() {"));
+        assert!(render_template(2022, 5).contains("get_answer(5, part, profile)"));
+        assert!(is_stub(&rendered, 18));
+        assert!(!is_stub(&rendered, 19));
+        let year_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/problems/year2022");
+        assert!(is_stub(&fs::read_to_string(year_root.join("day25.rs")).unwrap(), 25));
+        assert!(!is_stub(&fs::read_to_string(year_root.join("day06.rs")).unwrap(), 6));
+    }
+
+    #[test]
+    fn test_registers_in_order() {
+        assert_eq!(register_module("pub mod day01;\npub mod day03;\n", 2), Some("pub mod day01;\npub mod day02;\npub mod day03;\n".to_string()));
+        assert_eq!(register_module("pub mod day02;\n", 1), Some("pub mod day01;\npub mod day02;\n".to_string()));
+        assert_eq!(register_module("pub mod day02;\n", 2), None);
+        assert_eq!(
+            register_solver("        1 => Some(Box::new(day01::Problem01)),\n        _ => None,", 12),
+            Some("        1 => Some(Box::new(day01::Problem01)),\n        12 => Some(Box::new(day12::Problem12)),\n        _ => None,".to_string())
+        );
+        assert_eq!(register_year_module("pub mod year2022;\n", 2021), Some("pub mod year2021;\npub mod year2022;\n".to_string()));
+        assert_eq!(
+            register_year("    2022,\n];\n        2022 => year2022::registry::get_solver(day),\n", 2023),
+            Some("    2022,\n    2023,\n];\n        2022 => year2022::registry::get_solver(day),\n        2023 => year2023::registry::get_solver(day),\n".to_string())
+        );
+        assert_eq!(register_year("    2022,\n];\n        2022 => year2022::registry::get_solver(day),\n", 2022), None);
+    }
+
+    #[test]
+    fn test_scaffolds_a_new_day() {
+        let root = make_scratch_crate("new_day");
+        let scaffold = Scaffold::new(root.join("src"), root.join("input"));
+        assert_eq!(scaffold.scaffold(2022, 2).unwrap().len(), 5);
+
+        assert_eq!(fs::read_to_string(root.join("src/problems/year2022/day02.rs")).unwrap(), render_template(2022, 2));
+        assert!(fs::read_to_string(root.join("src/problems/year2022/mod.rs")).unwrap().starts_with("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+        assert!(fs::read_to_string(root.join("src/problems/year2022/registry.rs")).unwrap().contains("        2 => Some(Box::new(day02::Problem02)),\n        3 =>"));
+        assert_eq!(fs::read_to_string(root.join("input/2022/example_input_02.txt")).unwrap(), "");
+        assert_eq!(fs::read_to_string(root.join("input/2022/input_02.txt")).unwrap(), "");
+
+        // A stub can be scaffolded again, but inputs and registrations are kept as they are.
+        fs::write(root.join("input/2022/input_02.txt"), "1\n2\n").unwrap();
+        let done = scaffold.scaffold(2022, 2).unwrap();
+        assert!(done[1].starts_with("day 2 is already registered"), "{}", done[1]);
+        assert_eq!(fs::read_to_string(root.join("input/2022/input_02.txt")).unwrap(), "1\n2\n");
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_scaffolds_a_new_year() {
+        let root = make_scratch_crate("new_year");
+        let scaffold = Scaffold::new(root.join("src"), root.join("input"));
+        let done = scaffold.scaffold(2023, 5).unwrap();
+        assert!(done[0].starts_with("started 2023"), "{}", done[0]);
+
+        assert_eq!(fs::read_to_string(root.join("src/problems/mod.rs")).unwrap(), "pub mod year2022;\npub mod year2023;\n\npub mod registry;\n");
+        let registry = fs::read_to_string(root.join("src/problems/registry.rs")).unwrap();
+        assert!(registry.starts_with("const YEARS: &[usize] = &[\n    2022,\n    2023,\n];"), "{}", registry);
+        assert!(registry.contains("        2023 => year2023::registry::get_solver(day),\n        _ => None,"));
+        assert_eq!(fs::read_to_string(root.join("src/problems/year2023/mod.rs")).unwrap(), "pub mod day05;\n\npub mod registry;\n");
+        assert!(fs::read_to_string(root.join("src/problems/year2023/registry.rs")).unwrap().contains("        5 => Some(Box::new(day05::Problem05)),\n        _ => None,"));
+        assert!(fs::read_to_string(root.join("src/problems/year2023/day05.rs")).unwrap().contains("Source::Profile { year: 2023, day: 5, profile }"));
+        assert!(root.join("input/2023/input_05.txt").exists());
+
+        // Later days of the same year are added to its module and registry.
+        scaffold.scaffold(2023, 1).unwrap();
+        assert_eq!(fs::read_to_string(root.join("src/problems/year2023/mod.rs")).unwrap(), "pub mod day01;\npub mod day05;\n\npub mod registry;\n");
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_refuses_to_overwrite_real_code() {
+        let root = make_scratch_crate("real_code");
+        let module_path = root.join("src/problems/year2022/day03.rs");
+        let solved = render_template(2022, 3).replacen("    unimplemented!();", "    Ok(_input.len())", 1);
+        fs::write(&module_path, &solved).unwrap();
+
+        let scaffold = Scaffold::new(root.join("src"), root.join("input"));
+        assert!(scaffold.scaffold(2022, 3).unwrap_err().contains("won't be overwritten"));
+        assert_eq!(fs::read_to_string(&module_path).unwrap(), solved);
+        assert!(scaffold.scaffold(2022, 26).is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
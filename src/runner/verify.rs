@@ -0,0 +1,210 @@
+use std::fmt;
+
+use crate::input::input::InputParser;
+use crate::input::overrides::ParameterOverrides;
+use crate::input::profile::Profile;
+use crate::input::profiles::ProfileBook;
+use crate::problems::solution::Part;
+
+use super::{dispatch, run_all::{self, Status}};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String, actual: String },
+    Unknown { actual: String },
+    NoInput,
+    NoAnswer,
+    NotImplemented,
+    Error(String),
+}
+
+impl Verdict {
+
+    pub fn check(expected: Option<&str>, actual: Status) -> Self {
+        match (expected, actual) {
+            (_, Status::NotImplemented) => Self::NotImplemented,
+            (_, Status::Error(e)) => Self::Error(e),
+            (_, Status::Panicked(message)) => Self::Error(format!("panicked: {}", message)),
+            (None, Status::Ok(actual)) => Self::Unknown { actual },
+            (Some(expected), Status::Ok(actual)) if expected.trim() == actual.trim() => Self::Pass,
+            (Some(expected), Status::Ok(actual)) => Self::Fail { expected: expected.to_string(), actual },
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail { expected, actual } => write!(f, "FAIL     got {}, expected {}", first_line(actual), first_line(expected)),
+            Self::Unknown { actual } => write!(f, "unknown  got {}", first_line(actual)),
+            Self::NoInput => write!(f, "unknown  no input"),
+            Self::NoAnswer => write!(f, "unknown  no answer"),
+            Self::NotImplemented => write!(f, "not implemented"),
+            Self::Error(e) => write!(f, "error    {}", first_line(e)),
+        }
+    }
+}
+
+pub fn first_line(s: &str) -> String {
+    match s.lines().count() {
+        0 | 1 => s.to_string(),
+        _ => format!("{}...", s.lines().next().unwrap_or("")),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Check {
+    day: usize,
+    part: Part,
+    profile: Profile,
+    verdict: Verdict,
+}
+
+impl Check {
+
+    // A day that can't produce an answer, including one that panics, fails as surely as a wrong answer. Stubs aren't
+    // expected to produce one yet.
+    pub fn is_failure(&self) -> bool {
+        matches!(self.verdict, Verdict::Fail { .. } | Verdict::Error(_))
+    }
+}
+
+// Checks the given profile of each day, or otherwise the real profile along with every profile in the book. Answers
+// only hold for the profile's own parameters, so there are no overrides. Parts without an answer have nothing to be
+// checked against, so they're only solved if solve_unanswered is set, since some take minutes.
+pub fn verify(
+    parser: &InputParser, year: usize, days: &[usize], profile: Option<&Profile>, book: &ProfileBook, solve_unanswered: bool
+) -> Vec<Check> {
+    let overrides = ParameterOverrides::default();
+    let mut checks = Vec::new();
+    for day in days {
+        let profiles = match profile {
+            Some(profile) => vec![profile.clone()],
+            None => {
+                let mut profiles = book.get_profiles(*day);
+                if !profiles.contains(&Profile::Real) {
+                    profiles.insert(0, Profile::Real);
+                }
+                profiles
+            },
+        };
+        for profile in profiles {
+            // Without an input or an answer there's nothing to check, which is the case for days that haven't been
+            // started. Missing the input for an answer is still an error.
+            let has_input = parser.get_profile_path(year, *day, &profile).exists();
+            for part in [Part::A, Part::B] {
+                let expected = book.get_answer(*day, part, &profile);
+                let verdict = match (expected, has_input) {
+                    (None, false) => Verdict::NoInput,
+                    (None, true) if !solve_unanswered => Verdict::NoAnswer,
+                    _ => {
+                        let outcome = dispatch::silence_panics(|| run_all::run_one(parser, year, *day, part, &profile, book, &overrides));
+                        Verdict::check(expected, outcome.get_status().clone())
+                    },
+                };
+                checks.push(Check { day: *day, part, profile: profile.clone(), verdict });
+            }
+        }
+    }
+    checks
+}
+
+pub fn format_report(checks: &[Check]) -> String {
+    let mut lines = vec![format!("{:>3}  {:<4}  {:<10}  result", "day", "part", "profile")];
+    for check in checks {
+        lines.push(format!("{:>3}  {:<4}  {:<10}  {}", check.day, check.part.to_string(), check.profile.to_string(), check.verdict));
+    }
+    let count = |f: &dyn Fn(&Verdict) -> bool| checks.iter().filter(|check| f(&check.verdict)).count();
+    lines.push(format!(
+        "{} passed, {} failed, {} unknown, {} not implemented, {} errors",
+        count(&|v| *v == Verdict::Pass),
+        count(&|v| matches!(v, Verdict::Fail { .. })),
+        count(&|v| matches!(v, Verdict::Unknown { .. } | Verdict::NoInput | Verdict::NoAnswer)),
+        count(&|v| *v == Verdict::NotImplemented),
+        count(&|v| matches!(v, Verdict::Error(_))),
+    ));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test_verify {
+    use std::{env, fs, process};
+
+    use super::*;
+
+    #[test]
+    fn test_verdicts_compare_answers() {
+        assert_eq!(Verdict::check(Some("12"), Status::Ok("12".to_string())), Verdict::Pass);
+        assert_eq!(Verdict::check(Some("12"), Status::Ok("13".to_string())), Verdict::Fail { expected: "12".to_string(), actual: "13".to_string() });
+        assert_eq!(Verdict::check(None, Status::Ok("13".to_string())), Verdict::Unknown { actual: "13".to_string() });
+        assert_eq!(Verdict::check(Some("12"), Status::Error("bad input".to_string())), Verdict::Error("bad input".to_string()));
+        assert_eq!(Verdict::check(Some("12"), Status::Panicked("oops".to_string())), Verdict::Error("panicked: oops".to_string()));
+        assert_eq!(Verdict::check(Some("12"), Status::NotImplemented), Verdict::NotImplemented);
+    }
+
+    #[test]
+    fn test_verifies_days() {
+        let book = ProfileBook::from_string("\
+[day06.real]
+a = 1647
+b = 1
+
+[day07.example]
+a = 95437
+").unwrap();
+        // Day 18 is a stub, so any input reaches its unimplemented!().
+        let root = env::temp_dir().join(format!("verify_{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("2022")).unwrap();
+        for filename in ["input_06.txt", "input_07.txt", "example_input_07.txt"] {
+            fs::copy(InputParser::new().get_input_root().join("2022").join(filename), root.join("2022").join(filename)).unwrap();
+        }
+        fs::write(root.join("2022/input_18.txt"), "1\n").unwrap();
+
+        let checks = verify(&InputParser::new().with_input_root(&root), 2022, &[6, 7, 18], None, &book, true);
+        let answered_checks = verify(&InputParser::new().with_input_root(&root), 2022, &[6, 7, 18], None, &book, false);
+        fs::remove_dir_all(root).unwrap();
+        let report = format_report(&checks);
+        let lines = report.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines[1], "  6  a     real        pass");
+        assert_eq!(lines[2], "  6  b     real        FAIL     got 2447, expected 1");
+        assert_eq!(lines[3], "  7  a     real        unknown  got 1667443");
+        assert_eq!(lines[5], "  7  a     example     pass");
+        assert_eq!(lines[7], " 18  a     real        not implemented");
+        assert_eq!(lines[9], "2 passed, 1 failed, 3 unknown, 2 not implemented, 0 errors");
+        assert_eq!(checks.iter().filter(|check| check.is_failure()).count(), 1);
+        assert!(!checks[2].is_failure());
+        assert!(!checks[6].is_failure());
+
+        let report = format_report(&answered_checks);
+        let lines = report.lines().collect::<Vec<_>>();
+        assert_eq!(lines[3], "  7  a     real        unknown  no answer");
+        assert_eq!(lines[5], "  7  a     example     pass");
+        assert_eq!(lines[7], " 18  a     real        unknown  no answer");
+        assert_eq!(lines[9], "2 passed, 1 failed, 5 unknown, 0 not implemented, 0 errors");
+    }
+
+    #[test]
+    fn test_verifies_one_profile() {
+        let book = ProfileBook::from_string("[day13.example]\na = 13\nb = 140").unwrap();
+        let checks = verify(&InputParser::new(), 2022, &[13], Some(&Profile::Example), &book, false);
+        assert_eq!(format_report(&checks).lines().last(), Some("2 passed, 0 failed, 0 unknown, 0 not implemented, 0 errors"));
+    }
+
+    #[test]
+    fn test_only_misses_inputs_that_have_answers() {
+        let root = env::temp_dir().join(format!("verify_missing_{}", process::id()));
+        let parser = InputParser::new().with_input_root(&root);
+        let book = ProfileBook::from_string("[day19.real]\na = 1").unwrap();
+
+        let checks = verify(&parser, 2022, &[18, 19], None, &book, false);
+        assert_eq!(checks.iter().map(|check| check.verdict.clone()).take(3).collect::<Vec<_>>(), vec![Verdict::NoInput, Verdict::NoInput, Verdict::Error(
+            format!("There is no input for day 19 of 2022. Save it as {}, or set AOC_SESSION to your session cookie and run `fetch 19 --year 2022`.", root.join("2022").join("input_19.txt").display())
+        )]);
+        assert_eq!(checks[3].verdict, Verdict::NoInput);
+        assert_eq!(format_report(&checks).lines().last(), Some("0 passed, 0 failed, 3 unknown, 0 not implemented, 1 errors"));
+    }
+}
@@ -0,0 +1,164 @@
+use std::{fs, path::{Path, PathBuf}, thread, time::{Duration, Instant, SystemTime}};
+
+use crate::input::input::InputParser;
+use crate::input::profile::Profile;
+use crate::input::profiles;
+use crate::input::source::Source;
+use crate::problems::solution::Part;
+
+use super::{bench, dispatch};
+
+pub const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+// When a file was last modified and how long it was, or None while it doesn't exist.
+type Stamp = Option<(SystemTime, u64)>;
+
+fn get_stamp(path: &Path) -> Stamp {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+// Re-solves one part of a day whenever its input, its year's profiles or the config file changes. Everything is
+// reloaded on each run, so edits to parameters are picked up too, but changes to the code need a rebuild.
+pub struct Watch {
+    year: usize,
+    day: usize,
+    part: Part,
+    profile: Profile,
+    input: Option<Source>,
+    config: Option<Source>,
+    params: Vec<(String, String)>,
+    paths: Vec<PathBuf>,
+    stamps: Vec<Stamp>,
+    previous_time: Option<Duration>,
+}
+
+impl Watch {
+
+    pub fn new(
+        year: usize, day: usize, part: Part, profile: Profile, input: Option<Source>, config: Option<Source>, params: Vec<(String, String)>
+    ) -> Result<Self, String> {
+        let parser = InputParser::new();
+        let input_source = input.clone().unwrap_or(Source::Profile { year, day, profile: profile.clone() });
+        let mut paths = vec![
+            parser.get_path(&input_source).ok_or("Only inputs read from a file can be watched.".to_string())?,
+            parser.get_input_root().join(profiles::get_profiles_filename(year)),
+        ];
+        if let Some(config) = &config {
+            paths.push(parser.get_path(config).ok_or("Only a config read from a file can be watched.".to_string())?);
+        }
+        let stamps = paths.iter().map(|path| get_stamp(path)).collect();
+        Ok(Self { year, day, part, profile, input, config, params, paths, stamps, previous_time: None })
+    }
+
+    pub fn get_paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    // Returns whether any of the watched files changed since the last poll.
+    pub fn poll(&mut self) -> bool {
+        let stamps = self.paths.iter().map(|path| get_stamp(path)).collect::<Vec<_>>();
+        let has_changed = stamps != self.stamps;
+        self.stamps = stamps;
+        has_changed
+    }
+
+    // Solves the part and reports the answer along with how long it took, compared with the last run that succeeded.
+    pub fn run(&mut self) -> String {
+        let result = dispatch::catch_panics(|| {
+            let book = dispatch::load_profiles(self.year, None)?;
+            let overrides = dispatch::load_overrides(self.config.clone(), &self.params)?;
+            dispatch::check_overrides(self.year, &[self.day], &overrides)?;
+            let solver = dispatch::get_solver(self.year, self.day, &self.profile, &book, &overrides)?;
+            let input = dispatch::load_input(&InputParser::new(), self.year, self.day, &self.profile, self.input.clone())?;
+            let start = Instant::now();
+            let answer = solver.solve(self.part, &input).map_err(|e| e.to_string())?;
+            Ok((answer.to_string(), start.elapsed()))
+        });
+        match result {
+            Ok((answer, time)) => {
+                let timing = format_timing(time, self.previous_time);
+                self.previous_time = Some(time);
+                format!("{}\n{}", answer, timing)
+            },
+            Err(e) => e,
+        }
+    }
+
+    pub fn watch(mut self) -> Result<String, String> {
+        let paths = self.paths.iter().map(|path| path.display().to_string()).collect::<Vec<_>>();
+        println!("Watching {}. Press Ctrl-C to stop.", paths.join(", "));
+        loop {
+            let output = self.run();
+            println!("\nDay {} part {}:\n{}", self.day, self.part, output);
+            while !self.poll() {
+                thread::sleep(POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+pub fn format_timing(time: Duration, previous_time: Option<Duration>) -> String {
+    match previous_time {
+        Some(previous_time) if time >= previous_time => {
+            format!("took {} (+{} since the last run)", bench::format_duration(time), bench::format_duration(time - previous_time))
+        },
+        Some(previous_time) => {
+            format!("took {} (-{} since the last run)", bench::format_duration(time), bench::format_duration(previous_time - time))
+        },
+        None => format!("took {}", bench::format_duration(time)),
+    }
+}
+
+#[cfg(test)]
+mod test_watch {
+    use std::{env, process};
+
+    use super::*;
+
+    #[test]
+    fn test_formats_timing() {
+        assert_eq!(format_timing(Duration::from_micros(1500), None), "took 1.500ms");
+        assert_eq!(format_timing(Duration::from_micros(1500), Some(Duration::from_millis(1))), "took 1.500ms (+0.500ms since the last run)");
+        assert_eq!(format_timing(Duration::from_micros(500), Some(Duration::from_millis(1))), "took 0.500ms (-0.500ms since the last run)");
+    }
+
+    #[test]
+    fn test_reruns_when_the_input_changes() {
+        let root = env::temp_dir().join(format!("watch_{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        let path = root.join("input_01.txt");
+        fs::write(&path, "1\n2\n\n4\n").unwrap();
+
+        let mut watch = Watch::new(2022, 1, Part::A, Profile::Real, Some(path.clone().into()), None, Vec::new()).unwrap();
+        assert_eq!(watch.get_paths()[0], path);
+        assert!(!watch.poll());
+        let output = watch.run();
+        assert!(output.starts_with("4\ntook "), "{}", output);
+        assert!(!output.contains("since the last run"), "{}", output);
+
+        fs::write(&path, "1\n2\n\n4\n\n10\n").unwrap();
+        assert!(watch.poll());
+        assert!(!watch.poll());
+        let output = watch.run();
+        assert!(output.starts_with("10\ntook "), "{}", output);
+        assert!(output.ends_with("since the last run)"), "{}", output);
+
+        fs::remove_file(&path).unwrap();
+        assert!(watch.poll());
+        assert!(watch.run().starts_with("Unable to read"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_only_watches_files() {
+        assert!(Watch::new(2022, 1, Part::A, Profile::Real, Some(Source::Stdin), None, Vec::new()).is_err());
+        assert!(Watch::new(2022, 1, Part::A, Profile::Real, None, Some(Source::Stdin), Vec::new()).is_err());
+
+        let watch = Watch::new(2022, 7, Part::A, Profile::Example, None, None, Vec::new()).unwrap();
+        assert!(watch.get_paths()[0].ends_with("2022/example_input_07.txt"));
+        assert!(watch.get_paths()[1].ends_with("2022/profiles.toml"));
+    }
+}
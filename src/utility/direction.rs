@@ -1,7 +1,8 @@
-use super::vector::Vec2;
+use super::{error::ParseError, vector::Vec2};
 
 
 
+// Deltas have y pointing up, as in day 9's rope. Grids, whose rows run down the page, use get_grid_delta instead.
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 pub enum Direction {
     Left,
@@ -12,13 +13,38 @@ pub enum Direction {
 
 impl Direction {
 
-    pub fn from_string(s: &str) -> Result<Self, String> {
+    // In the order of their facing scores.
+    pub fn all() -> [Self; 4] {
+        [Self::Right, Self::Down, Self::Left, Self::Up]
+    }
+
+    pub fn from_string(s: &str) -> Result<Self, ParseError> {
         match s.to_lowercase().as_str() {
-            "left" | "l" => Ok(Self::Left),
-            "right" | "r" => Ok(Self::Right),
-            "down" | "d" => Ok(Self::Down),
-            "up" | "u" => Ok(Self::Up),
-            _ => Err(format!("Unable to parse Direction from string: {}", s))
+            "left" | "l" | "<" | "w" | "west" => Ok(Self::Left),
+            "right" | "r" | ">" | "e" | "east" => Ok(Self::Right),
+            "down" | "d" | "v" | "s" | "south" => Ok(Self::Down),
+            "up" | "u" | "^" | "n" | "north" => Ok(Self::Up),
+            _ => Err(ParseError::unexpected_token(1, 1, "a direction", s))
+        }
+    }
+
+    // Reads the arrows ^v<> and the compass letters NSEW in either case, like from_string.
+    pub fn from_char(c: char) -> Result<Self, ParseError> {
+        match c {
+            '<' | 'W' | 'w' => Ok(Self::Left),
+            '>' | 'E' | 'e' => Ok(Self::Right),
+            'v' | 'S' | 's' => Ok(Self::Down),
+            '^' | 'N' | 'n' => Ok(Self::Up),
+            _ => Err(ParseError::unexpected_token(1, 1, "one of ^v<> or NSEW", &c.to_string()))
+        }
+    }
+
+    pub fn to_arrow(&self) -> char {
+        match self {
+            Self::Left => '<',
+            Self::Right => '>',
+            Self::Down => 'v',
+            Self::Up => '^',
         }
     }
 
@@ -30,10 +56,201 @@ impl Direction {
             Self::Up => Vec2::new(0, 1),
         }
     }
+
+    // The step in a Grid, where going up means going back a row.
+    pub fn get_grid_delta(&self) -> Vec2 {
+        match self {
+            Self::Left => Vec2::new(-1, 0),
+            Self::Right => Vec2::new(1, 0),
+            Self::Down => Vec2::new(0, 1),
+            Self::Up => Vec2::new(0, -1),
+        }
+    }
+
+    // Anticlockwise, so Up turns to Left.
+    pub fn turn_left(&self) -> Self {
+        match self {
+            Self::Left => Self::Down,
+            Self::Right => Self::Up,
+            Self::Down => Self::Right,
+            Self::Up => Self::Left,
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        self.turn_left().opposite()
+    }
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+            Self::Up => Self::Down,
+        }
+    }
+
+    // Day 22's password counts Right as 0, then goes clockwise.
+    pub fn get_facing_score(&self) -> usize {
+        match self {
+            Self::Right => 0,
+            Self::Down => 1,
+            Self::Left => 2,
+            Self::Up => 3,
+        }
+    }
+}
+
+// The eight points of the compass, with north up like Direction::Up.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum CompassDirection {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl CompassDirection {
+
+    // Clockwise from north.
+    pub fn all() -> [Self; 8] {
+        [Self::N, Self::NE, Self::E, Self::SE, Self::S, Self::SW, Self::W, Self::NW]
+    }
+
+    pub fn from_string(s: &str) -> Result<Self, ParseError> {
+        match s.to_uppercase().as_str() {
+            "N" => Ok(Self::N),
+            "NE" => Ok(Self::NE),
+            "E" => Ok(Self::E),
+            "SE" => Ok(Self::SE),
+            "S" => Ok(Self::S),
+            "SW" => Ok(Self::SW),
+            "W" => Ok(Self::W),
+            "NW" => Ok(Self::NW),
+            _ => Err(ParseError::unexpected_token(1, 1, "a compass direction", s))
+        }
+    }
+
+    pub fn get_delta(&self) -> Vec2 {
+        match self {
+            Self::N => Vec2::new(0, 1),
+            Self::NE => Vec2::new(1, 1),
+            Self::E => Vec2::new(1, 0),
+            Self::SE => Vec2::new(1, -1),
+            Self::S => Vec2::new(0, -1),
+            Self::SW => Vec2::new(-1, -1),
+            Self::W => Vec2::new(-1, 0),
+            Self::NW => Vec2::new(-1, 1),
+        }
+    }
+
+    // An eighth of a turn anticlockwise.
+    pub fn turn_left(&self) -> Self {
+        Self::all()[(self.get_index() + 7) % 8]
+    }
+
+    // An eighth of a turn clockwise.
+    pub fn turn_right(&self) -> Self {
+        Self::all()[(self.get_index() + 1) % 8]
+    }
+
+    pub fn opposite(&self) -> Self {
+        Self::all()[(self.get_index() + 4) % 8]
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        self.get_index() % 2 == 1
+    }
+
+    fn get_index(&self) -> usize {
+        Self::all().iter().position(|d| d == self).expect("Every direction is in all.")
+    }
+}
+
+impl From<Direction> for CompassDirection {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Left => Self::W,
+            Direction::Right => Self::E,
+            Direction::Down => Self::S,
+            Direction::Up => Self::N,
+        }
+    }
 }
 
 #[cfg(test)]
 mod test_super {
+    use std::collections::HashSet;
+
     use super::*;
 
-}
\ No newline at end of file
+    #[test]
+    fn test_direction_turns() {
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Left.turn_left(), Direction::Down);
+        assert_eq!(Direction::Right.turn_right(), Direction::Down);
+        for direction in Direction::all() {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_left().turn_left(), direction.opposite());
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_eq!(direction.opposite().get_delta(), direction.get_delta() * -1);
+            assert_eq!(direction.turn_right().get_facing_score(), (direction.get_facing_score() + 1) % 4);
+        }
+    }
+
+    #[test]
+    fn test_direction_deltas() {
+        assert_eq!(Direction::all().iter().map(|d| d.get_delta()).collect::<HashSet<_>>().len(), 4);
+        assert!(Direction::all().iter().all(|d| d.get_delta().get_l1_norm() == 1));
+        assert_eq!(Direction::Up.get_delta(), Vec2::j());
+        assert_eq!(Direction::Up.get_grid_delta(), Vec2::j() * -1);
+        assert_eq!(Direction::Right.get_grid_delta(), Vec2::i());
+        assert!(Direction::all().iter().all(|d| d.get_grid_delta() == Vec2::new(d.get_delta().x(), -d.get_delta().y())));
+        assert_eq!(Direction::all().map(|d| d.get_facing_score()), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_parses_directions() {
+        assert_eq!(Direction::from_string("L").unwrap(), Direction::Left);
+        assert_eq!(Direction::from_string("up").unwrap(), Direction::Up);
+        assert_eq!(Direction::from_string("S").unwrap(), Direction::Down);
+        assert!(Direction::from_string("sideways").is_err());
+
+        assert_eq!("^v<>".chars().map(|c| Direction::from_char(c).unwrap()).collect::<Vec<_>>(), vec![Direction::Up, Direction::Down, Direction::Left, Direction::Right]);
+        assert_eq!("NSWE".chars().map(|c| Direction::from_char(c).unwrap()).collect::<Vec<_>>(), vec![Direction::Up, Direction::Down, Direction::Left, Direction::Right]);
+        assert_eq!("nswe".chars().map(|c| Direction::from_char(c).unwrap()).collect::<Vec<_>>(), vec![Direction::Up, Direction::Down, Direction::Left, Direction::Right]);
+        assert_eq!(Direction::from_string("n").unwrap(), Direction::from_char('n').unwrap());
+        assert!(Direction::from_char('x').is_err());
+        for direction in Direction::all() {
+            assert_eq!(Direction::from_char(direction.to_arrow()).unwrap(), direction);
+        }
+    }
+
+    #[test]
+    fn test_compass_directions() {
+        assert_eq!(CompassDirection::all().iter().map(|d| d.get_delta()).collect::<HashSet<_>>().len(), 8);
+        assert_eq!(CompassDirection::NE.get_delta(), Vec2::new(1, 1));
+        assert_eq!(CompassDirection::SW.get_delta(), Vec2::new(-1, -1));
+        assert_eq!(CompassDirection::N.turn_left(), CompassDirection::NW);
+        assert_eq!(CompassDirection::NW.turn_right(), CompassDirection::N);
+        assert_eq!(CompassDirection::SE.opposite(), CompassDirection::NW);
+        assert!(CompassDirection::SE.is_diagonal());
+        assert!(!CompassDirection::S.is_diagonal());
+        for direction in CompassDirection::all() {
+            assert_eq!(direction.opposite().get_delta(), direction.get_delta() * -1);
+            assert_eq!(direction.turn_right().turn_left(), direction);
+        }
+        for direction in Direction::all() {
+            assert_eq!(CompassDirection::from(direction).get_delta(), direction.get_delta());
+        }
+
+        assert_eq!(CompassDirection::from_string("ne").unwrap(), CompassDirection::NE);
+        assert!(CompassDirection::from_string("NNE").is_err());
+    }
+
+}
@@ -0,0 +1,88 @@
+use std::{error::Error, fmt, io, path::PathBuf, str::FromStr};
+
+#[derive(Debug)]
+pub enum ParseError {
+    Io { path: PathBuf, source: io::Error },
+    Invalid { line: usize, column: usize, message: String },
+    UnexpectedToken { line: usize, column: usize, expected: String, found: String },
+    Malformed(String),
+    Unavailable(String),
+}
+
+impl ParseError {
+
+    pub fn io(path: PathBuf, source: io::Error) -> Self {
+        Self::Io { path, source }
+    }
+
+    pub fn invalid(line: usize, column: usize, message: String) -> Self {
+        Self::Invalid { line, column, message }
+    }
+
+    pub fn unexpected_token(line: usize, column: usize, expected: &str, found: &str) -> Self {
+        Self::UnexpectedToken { line, column, expected: expected.to_string(), found: found.to_string() }
+    }
+
+    // Lines are 1-indexed; errors raised while parsing a single line report line 1 and callers shift them into place.
+    pub fn offset_lines(self, n_lines: usize) -> Self {
+        match self {
+            Self::Io { .. } | Self::Malformed(_) | Self::Unavailable(_) => self,
+            Self::Invalid { line, column, message } => Self::Invalid { line: line + n_lines, column, message },
+            Self::UnexpectedToken { line, column, expected, found } => Self::UnexpectedToken { line: line + n_lines, column, expected, found },
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io { path, source } => write!(f, "Unable to read {}: {}", path.display(), source),
+            Self::Invalid { line, column, message } => write!(f, "Line {}, column {}: {}", line, column, message),
+            Self::UnexpectedToken { line, column, expected, found } => {
+                write!(f, "Line {}, column {}: expected {:?}, but got {:?}", line, column, expected, found)
+            },
+            Self::Malformed(message) | Self::Unavailable(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+pub fn parse_number<T: FromStr>(s: &str, column: usize) -> Result<T, ParseError> where T::Err: fmt::Display {
+    s.trim().parse::<T>().map_err(|e| ParseError::invalid(1, column, format!("Unable to parse {:?} as a number: {}", s, e)))
+}
+
+#[cfg(test)]
+mod test_error {
+    use super::*;
+
+    #[test]
+    fn test_error_displays_location() {
+        let error = ParseError::unexpected_token(3, 7, "x=", "y=");
+        assert_eq!(error.to_string(), "Line 3, column 7: expected \"x=\", but got \"y=\"");
+
+        let error = ParseError::invalid(1, 2, "Not a number: q".to_string()).offset_lines(4);
+        assert_eq!(error.to_string(), "Line 5, column 2: Not a number: q");
+    }
+
+    #[test]
+    fn test_error_keeps_io_source() {
+        let error = ParseError::io(PathBuf::from("input/missing.txt"), io::Error::new(io::ErrorKind::NotFound, "not found"));
+
+        assert!(error.to_string().contains("input/missing.txt"));
+        assert_eq!(error.source().unwrap().to_string(), "not found");
+    }
+
+    #[test]
+    fn test_error_parses_numbers() {
+        assert_eq!(parse_number::<i32>(" -12", 4).unwrap(), -12);
+        assert!(parse_number::<u32>("-12", 4).unwrap_err().to_string().starts_with("Line 1, column 4: Unable to parse \"-12\""));
+    }
+}
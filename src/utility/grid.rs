@@ -0,0 +1,354 @@
+use std::{collections::HashMap, fmt, iter, ops::{Index, IndexMut}, str::FromStr};
+
+use super::{error::ParseError, vector::Vec2};
+
+// Positions are (column, row), so Vec2::i() steps right along a row and Vec2::j() steps down to the next one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    backend: Backend<T>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Backend<T> {
+    // Every cell of a rectangle whose top left corner is the origin.
+    Dense(Vec<Vec<T>>),
+    // Only the cells that have been set. Everything else reads as the default, however far out it is.
+    Sparse { cells: HashMap<Vec2, T>, default: T },
+}
+
+impl<T> Grid<T> {
+
+    pub fn dense(rows: Vec<Vec<T>>) -> Result<Self, ParseError> {
+        if rows.is_empty() || rows[0].is_empty() {
+            return Err(ParseError::Malformed("The grid should have at least one cell.".to_string()));
+        }
+        if let Some(i) = rows.iter().position(|row| row.len() != rows[0].len()) {
+            return Err(ParseError::invalid(i + 1, 1, format!("Expected {} cells in the row but got {}.", rows[0].len(), rows[i].len())));
+        }
+        Ok(Self { backend: Backend::Dense(rows) })
+    }
+
+    pub fn sparse(default: T) -> Self {
+        Self::from_cells(Vec::new(), default)
+    }
+
+    pub fn from_cells(cells: impl IntoIterator<Item = (Vec2, T)>, default: T) -> Self {
+        Self { backend: Backend::Sparse { cells: cells.into_iter().collect(), default } }
+    }
+
+    // Each line of the map is a row and each character a cell.
+    pub fn from_char_map(map: &str, to_cell: impl Fn(char) -> Result<T, String>) -> Result<Self, ParseError> {
+        let rows = map.lines().enumerate().map(
+            |(i, line)| line.chars().enumerate().map(
+                |(j, c)| to_cell(c).map_err(|e| ParseError::invalid(i + 1, j + 1, format!("Unable to parse {:?}: {}", c, e)))
+            ).collect()
+        ).collect::<Result<Vec<Vec<T>>, ParseError>>()?;
+        Self::dense(rows)
+    }
+
+    // Keeps only the cells that differ from the default.
+    pub fn into_sparse(self, default: T) -> Self where T: PartialEq {
+        let cells = match self.backend {
+            Backend::Dense(rows) => rows.into_iter().enumerate().flat_map(
+                |(y, row)| row.into_iter().enumerate().map(move |(x, cell)| (Vec2::new(x as i32, y as i32), cell))
+            ).collect::<Vec<_>>(),
+            Backend::Sparse { cells, .. } => cells.into_iter().collect(),
+        };
+        let cells = cells.into_iter().filter(|(_, cell)| *cell != default).collect::<Vec<_>>();
+        Self::from_cells(cells, default)
+    }
+
+    pub fn is_dense(&self) -> bool {
+        matches!(self.backend, Backend::Dense(_))
+    }
+
+    // How many cells are stored, which for a sparse grid is only those that have been set.
+    pub fn len(&self) -> usize {
+        match &self.backend {
+            Backend::Dense(rows) => rows.len() * rows[0].len(),
+            Backend::Sparse { cells, .. } => cells.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, v: Vec2) -> Option<&T> {
+        match &self.backend {
+            Backend::Dense(rows) if self.is_in_bounds(v) => Some(&rows[v.y() as usize][v.x() as usize]),
+            Backend::Dense(_) => None,
+            Backend::Sparse { cells, default } => Some(cells.get(&v).unwrap_or(default)),
+        }
+    }
+
+    // Setting a cell outside a dense grid is a bug, so this panics rather than growing the grid.
+    pub fn set(&mut self, v: Vec2, value: T) {
+        assert!(self.is_in_bounds(v), "{:?} is outside the grid", v);
+        match &mut self.backend {
+            Backend::Dense(rows) => { rows[v.y() as usize][v.x() as usize] = value; },
+            Backend::Sparse { cells, .. } => { cells.insert(v, value); },
+        }
+    }
+
+    // A sparse grid has no edges.
+    pub fn is_in_bounds(&self, v: Vec2) -> bool {
+        match &self.backend {
+            Backend::Dense(rows) => (v.x() >= 0) && (v.y() >= 0) && (v.x() < rows[0].len() as i32) && (v.y() < rows.len() as i32),
+            Backend::Sparse { .. } => true,
+        }
+    }
+
+    // The top left and bottom right corners of the smallest rectangle holding every stored cell.
+    pub fn get_bounds(&self) -> Option<(Vec2, Vec2)> {
+        match &self.backend {
+            Backend::Dense(rows) => Some((Vec2::new(0, 0), Vec2::new(rows[0].len() as i32 - 1, rows.len() as i32 - 1))),
+            Backend::Sparse { cells, .. } => {
+                let min_x = cells.keys().map(|v| v.x()).min()?;
+                let min_y = cells.keys().map(|v| v.y()).min()?;
+                let max_x = cells.keys().map(|v| v.x()).max()?;
+                let max_y = cells.keys().map(|v| v.y()).max()?;
+                Some((Vec2::new(min_x, min_y), Vec2::new(max_x, max_y)))
+            },
+        }
+    }
+
+    // The width and height of the bounds.
+    pub fn get_dimensions(&self) -> (usize, usize) {
+        match self.get_bounds() {
+            None => (0, 0),
+            Some((top_left, bottom_right)) => {
+                let size = bottom_right - top_left + Vec2::new(1, 1);
+                (size.x() as usize, size.y() as usize)
+            },
+        }
+    }
+
+    // Every position within the bounds, row by row.
+    pub fn get_positions(&self) -> Vec<Vec2> {
+        match self.get_bounds() {
+            None => Vec::new(),
+            Some((top_left, bottom_right)) => (top_left.y()..=bottom_right.y()).flat_map(
+                |y| (top_left.x()..=bottom_right.x()).map(move |x| Vec2::new(x, y))
+            ).collect(),
+        }
+    }
+
+    // The stored cells, row by row for a dense grid and in no particular order for a sparse one.
+    pub fn iter(&self) -> Box<dyn Iterator<Item = (Vec2, &T)> + '_> {
+        match &self.backend {
+            Backend::Dense(rows) => Box::new(rows.iter().enumerate().flat_map(
+                |(y, row)| row.iter().enumerate().map(move |(x, cell)| (Vec2::new(x as i32, y as i32), cell))
+            )),
+            Backend::Sparse { cells, .. } => Box::new(cells.iter().map(|(v, cell)| (*v, cell))),
+        }
+    }
+
+    pub fn get_row(&self, y: i32) -> impl Iterator<Item = &T> + '_ {
+        let columns = self.get_bounds().into_iter().flat_map(|(top_left, bottom_right)| top_left.x()..=bottom_right.x());
+        columns.filter_map(move |x| self.get(Vec2::new(x, y)))
+    }
+
+    pub fn get_column(&self, x: i32) -> impl Iterator<Item = &T> + '_ {
+        let rows = self.get_bounds().into_iter().flat_map(|(top_left, bottom_right)| top_left.y()..=bottom_right.y());
+        rows.filter_map(move |y| self.get(Vec2::new(x, y)))
+    }
+
+    // The positions seen looking from v in steps of step, nearest first, until the view leaves the bounds.
+    pub fn get_line_of_sight(&self, v: Vec2, step: Vec2) -> impl Iterator<Item = Vec2> {
+        assert!(step != Vec2::default(), "The line of sight needs a direction to look in");
+        let bounds = self.get_bounds();
+        let is_within_bounds = move |w: &Vec2| match bounds {
+            None => false,
+            Some((top_left, bottom_right)) => {
+                (top_left.x()..=bottom_right.x()).contains(&w.x()) && (top_left.y()..=bottom_right.y()).contains(&w.y())
+            },
+        };
+        let mut next = v;
+        iter::from_fn(move || {
+            next += step;
+            Some(next)
+        }).take_while(is_within_bounds)
+    }
+
+    pub fn get_neighbors4(&self, v: Vec2) -> Vec<Vec2> {
+        vec![
+            v - Vec2::i(),
+            v + Vec2::i(),
+            v - Vec2::j(),
+            v + Vec2::j(),
+        ].into_iter().filter(|w| self.is_in_bounds(*w)).collect()
+    }
+
+    pub fn get_neighbors8(&self, v: Vec2) -> Vec<Vec2> {
+        (-1..=1).flat_map(|dx| (-1..=1).map(move |dy| Vec2::new(dx, dy)))
+            .filter(|delta| *delta != Vec2::default())
+            .map(|delta| v + delta)
+            .filter(|w| self.is_in_bounds(*w))
+            .collect()
+    }
+
+    // Draws the cells within the bounds, one line per row.
+    pub fn render(&self, to_char: impl Fn(&T) -> char) -> String {
+        let (_, n_rows) = self.get_dimensions();
+        let first_row = self.get_bounds().map_or(0, |(top_left, _)| top_left.y());
+        (first_row..(first_row + n_rows as i32))
+            .map(|y| self.get_row(y).map(&to_char).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+}
+
+impl<T: Clone> Grid<T> {
+
+    // Reading an unset cell of a sparse grid mutably sets it to the default first.
+    pub fn get_mut(&mut self, v: Vec2) -> Option<&mut T> {
+        let is_in_bounds = self.is_in_bounds(v);
+        match &mut self.backend {
+            Backend::Dense(rows) if is_in_bounds => Some(&mut rows[v.y() as usize][v.x() as usize]),
+            Backend::Dense(_) => None,
+            Backend::Sparse { cells, default } => Some(cells.entry(v).or_insert_with(|| default.clone())),
+        }
+    }
+}
+
+impl<T: Default> Default for Grid<T> {
+    fn default() -> Self {
+        Self::sparse(T::default())
+    }
+}
+
+impl<T> Index<Vec2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, v: Vec2) -> &Self::Output {
+        self.get(v).unwrap_or_else(|| panic!("{:?} is outside the grid", v))
+    }
+}
+
+impl<T: Clone> IndexMut<Vec2> for Grid<T> {
+    fn index_mut(&mut self, v: Vec2) -> &mut Self::Output {
+        self.get_mut(v).unwrap_or_else(|| panic!("{:?} is outside the grid", v))
+    }
+}
+
+impl<T: FromStr> FromStr for Grid<T> where T::Err: fmt::Display {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_char_map(s, |c| c.to_string().parse::<T>().map_err(|e| e.to_string()))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (_, n_rows) = self.get_dimensions();
+        let first_row = self.get_bounds().map_or(0, |(top_left, _)| top_left.y());
+        for y in first_row..(first_row + n_rows as i32) {
+            if y != first_row {
+                writeln!(f)?;
+            }
+            for cell in self.get_row(y) {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_grid {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn get_example_grid() -> Grid<usize> {
+        "\
+30373
+25512
+65332".parse().unwrap()
+    }
+
+    #[test]
+    fn test_parses_character_maps() {
+        let grid = get_example_grid();
+        assert!(grid.is_dense());
+        assert_eq!(grid.get_dimensions(), (5, 3));
+        assert_eq!(grid[Vec2::new(0, 0)], 3);
+        assert_eq!(grid[Vec2::new(4, 2)], 2);
+        assert_eq!(grid[Vec2::new(3, 0)], 7);
+        assert_eq!(grid.get(Vec2::new(0, 3)), None);
+        assert_eq!(grid.get(Vec2::new(-1, 0)), None);
+
+        let error = "12\n1x".parse::<Grid<usize>>().unwrap_err();
+        assert!(error.to_string().starts_with("Line 2, column 2: Unable to parse 'x'"), "{}", error);
+        assert!("123\n12".parse::<Grid<usize>>().is_err());
+        assert!("".parse::<Grid<usize>>().is_err());
+
+        let walls = Grid::from_char_map("#.\n.#", |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("expected # or .".to_string()),
+        }).unwrap();
+        assert_eq!(walls.render(|wall| if *wall { '#' } else { '.' }), "#.\n.#");
+    }
+
+    #[test]
+    fn test_gets_rows_columns_and_lines_of_sight() {
+        let grid = get_example_grid();
+        assert_eq!(grid.get_row(1).cloned().collect::<Vec<_>>(), vec![2, 5, 5, 1, 2]);
+        assert_eq!(grid.get_column(3).cloned().collect::<Vec<_>>(), vec![7, 1, 3]);
+        assert_eq!(grid.get_row(3).count(), 0);
+
+        assert_eq!(
+            grid.get_line_of_sight(Vec2::new(2, 1), Vec2::i()).collect::<Vec<_>>(),
+            vec![Vec2::new(3, 1), Vec2::new(4, 1)]
+        );
+        assert_eq!(
+            grid.get_line_of_sight(Vec2::new(2, 1), Vec2::new(-1, -1)).collect::<Vec<_>>(),
+            vec![Vec2::new(1, 0)]
+        );
+        assert_eq!(grid.get_line_of_sight(Vec2::new(2, 2), Vec2::j()).count(), 0);
+    }
+
+    #[test]
+    fn test_gets_neighbors() {
+        let grid = get_example_grid();
+        let neighbors4 = grid.get_neighbors4(Vec2::new(0, 0)).into_iter().collect::<HashSet<_>>();
+        assert_eq!(neighbors4, vec![Vec2::new(1, 0), Vec2::new(0, 1)].into_iter().collect());
+        assert_eq!(grid.get_neighbors4(Vec2::new(1, 1)).len(), 4);
+        assert_eq!(grid.get_neighbors8(Vec2::new(0, 0)).len(), 3);
+        assert_eq!(grid.get_neighbors8(Vec2::new(1, 1)).len(), 8);
+
+        let sparse = Grid::sparse(0);
+        assert_eq!(sparse.get_neighbors8(Vec2::new(100, -100)).len(), 8);
+    }
+
+    #[test]
+    fn test_sparse_grids_default_unset_cells() {
+        let mut grid = Grid::sparse('.');
+        assert!(grid.is_empty());
+        assert_eq!(grid.get_bounds(), None);
+        assert_eq!(grid.to_string(), "");
+
+        grid.set(Vec2::new(3, -1), '#');
+        grid[Vec2::new(6, 1)] = 'o';
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid[Vec2::new(0, 0)], '.');
+        assert_eq!(grid.get_bounds(), Some((Vec2::new(3, -1), Vec2::new(6, 1))));
+        assert_eq!(grid.get_dimensions(), (4, 3));
+        assert_eq!(grid.to_string(), "#...\n....\n...o");
+        assert_eq!(grid.get_line_of_sight(Vec2::new(3, -1), Vec2::j()).count(), 2);
+    }
+
+    #[test]
+    fn test_converts_dense_grids_to_sparse() {
+        let grid = get_example_grid().into_sparse(3);
+        assert!(!grid.is_dense());
+        assert_eq!(grid.len(), 10);
+        assert_eq!(grid[Vec2::new(0, 0)], 3);
+        assert_eq!(grid[Vec2::new(1, 1)], 5);
+        assert_eq!(grid.to_string(), "30373\n25512\n65332");
+    }
+}
@@ -1,3 +1,6 @@
 pub mod direction;
 pub mod vector;
-pub mod parser;
\ No newline at end of file
+pub mod parser;
+pub mod error;
+pub mod grid;
+pub mod search;
\ No newline at end of file
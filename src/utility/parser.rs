@@ -1,15 +1,21 @@
-use std::collections::HashSet;
+use std::{fmt::Display, str::FromStr};
 
+use super::error::{ParseError, parse_number};
+
+// The pointer is a byte offset into to_parse and always sits on a char boundary. The line and column it points
+// at are kept up to date as input is consumed, so reporting a location doesn't rescan the input.
 #[derive(Debug)]
 pub struct Parser {
     to_parse: String,
     pointer: usize,
+    line: usize,
+    column: usize,
 }
 
 impl Parser {
 
     pub fn new(commands: String) -> Self {
-        Self { to_parse: commands, pointer: 0 }
+        Self { to_parse: commands, pointer: 0, line: 1, column: 1 }
     }
 
     pub fn n_remaining_to_parse(&self) -> usize {
@@ -17,84 +23,326 @@ impl Parser {
     }
 
     pub fn increment_pointer(&mut self, n: usize) {
-        let new_pointer_value = self.pointer + n;
-        if new_pointer_value > self.to_parse.len() {
-            self.pointer = self.to_parse.len();
-        } else {
-            self.pointer = new_pointer_value;
-        }
+        let _skipped = self.consume_n(n);
+    }
+
+    pub fn get_line(&self) -> usize {
+        self.line
+    }
+
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+
+    pub fn get_location(&self) -> (usize, usize) {
+        (self.line, self.column)
+    }
+
+    pub fn unexpected_token(&self, expected: &str, found: &str) -> ParseError {
+        ParseError::unexpected_token(self.line, self.column, expected, found)
     }
 
     pub fn peek_char(&self) -> Option<char> {
-        self.to_parse.chars().nth(self.pointer)
+        self.remaining().chars().next()
     }
 
     pub fn consume_line(&mut self) -> String {
-        for (i, c) in self.to_parse.chars().skip(self.pointer).enumerate() {
-            if c == '\n' {
-                let to_return = self.consume_n(i);
-                let _new_line = self.consume_n(1);
-                return to_return;
-            }
+        match self.remaining().find('\n') {
+            Some(i) => {
+                let to_return = self.consume_bytes(i);
+                let _new_line = self.consume_bytes(1);
+                to_return
+            },
+            None => self.consume_remaining(),
         }
-        self.consume_remaining()
     }
 
     pub fn consume_until(&mut self, condition: &dyn Fn(char) -> bool) -> String {
-        for (i, c) in self.to_parse.chars().skip(self.pointer).enumerate() {
-            if condition(c) {
-                let to_return = self.consume_n(i);
-                return to_return;
-            }
+        match self.remaining().find(condition) {
+            Some(i) => self.consume_bytes(i),
+            None => self.consume_remaining(),
         }
-        self.consume_remaining()
+    }
+
+    pub fn consume_number<T: FromStr>(&mut self, condition: &dyn Fn(char) -> bool) -> Result<T, ParseError> where T::Err: Display {
+        let (line, column) = self.get_location();
+        let s = self.consume_until(condition);
+        parse_number(&s, column).map_err(|e| e.offset_lines(line - 1))
     }
 
     pub fn consume_whitespace(&mut self) -> String {
-        self.consume_until(&|ch| !Self::whitespace_chars().contains(&ch))
+        self.consume_until(&|ch| !Self::is_whitespace(ch))
     }
 
     pub fn consume_until_whitespace(&mut self) -> String {
-        self.consume_until(&|ch| Self::whitespace_chars().contains(&ch))
+        self.consume_until(&Self::is_whitespace)
     }
 
-    fn whitespace_chars() -> HashSet<char> {
-        vec![' ', '\t', '\n'].into_iter().collect::<HashSet<_>>()
+    fn is_whitespace(ch: char) -> bool {
+        matches!(ch, ' ' | '\t' | '\n')
     }
 
     pub fn consume_remaining(&mut self) -> String {
-        self.consume_n(self.n_remaining_to_parse())
+        self.consume_bytes(self.n_remaining_to_parse())
     }
 
+    // Consumes up to n characters, which may be more than n bytes.
     pub fn consume_n(&mut self, n: usize) -> String {
-        let to_return = if self.pointer + n > self.to_parse.len() {
-            self.to_parse[self.pointer..self.to_parse.len()].to_string()
-        } else {
-            self.to_parse[self.pointer..(self.pointer + n)].to_string()
-        };
-        self.increment_pointer(n);
-        return to_return;
+        match self.remaining().char_indices().nth(n) {
+            Some((i, _)) => self.consume_bytes(i),
+            None => self.consume_remaining(),
+        }
+    }
+
+    fn remaining(&self) -> &str {
+        &self.to_parse[self.pointer..]
     }
 
-    pub fn expect_end_of_line_or_file(&mut self) -> Result<String, String> {
-        if self.pointer == self.to_parse.len() {
+    // n_bytes must land on a char boundary.
+    fn consume_bytes(&mut self, n_bytes: usize) -> String {
+        let to_return = self.remaining()[..n_bytes].to_string();
+        for c in to_return.chars() {
+            if c == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+        self.pointer += n_bytes;
+        to_return
+    }
+
+    pub fn expect_end_of_line_or_file(&mut self) -> Result<String, ParseError> {
+        if self.n_remaining_to_parse() == 0 {
             return Ok("".to_string());
         }
-        self.expect_end_of_line().map_err(|_| format!("Expected end of file but got: {}", self.consume_remaining()))
+        let error = self.unexpected_token("end of line or file", self.remaining());
+        self.expect_end_of_line().map_err(|_| error)
     }
 
-    pub fn expect_end_of_line(&mut self) -> Result<String, String> {
+    pub fn expect_end_of_line(&mut self) -> Result<String, ParseError> {
         self.expect("\n")
     }
 
-    pub fn expect(&mut self, s: &str) -> Result<String, String> {
-        let length = s.len();
-        let result = self.consume_n(length);
+    pub fn expect(&mut self, s: &str) -> Result<String, ParseError> {
+        let location = self.get_location();
+        let result = self.consume_n(s.chars().count());
         if result == s {
-            return Ok(result);
+            Ok(result)
         } else {
-            return Err(format!("Expected {}, but got {}", s, result));
+            Err(ParseError::unexpected_token(location.0, location.1, s, &result))
+        }
+    }
+
+    // Combinators. Unlike the consume_* methods above, these leave the pointer where it was when they fail,
+    // so alternatives can be tried one after another.
+
+    pub fn parse_all<T>(s: &str, item: impl FnOnce(&mut Self) -> Result<T, ParseError>) -> Result<T, ParseError> {
+        let mut parser = Self::new(s.to_string());
+        let to_return = item(&mut parser)?;
+        parser.end_of_input()?;
+        Ok(to_return)
+    }
+
+    pub fn end_of_input(&self) -> Result<(), ParseError> {
+        if self.n_remaining_to_parse() == 0 {
+            return Ok(());
+        }
+        Err(self.unexpected_token("end of input", self.remaining()))
+    }
+
+    pub fn tag(&mut self, s: &str) -> Result<String, ParseError> {
+        if self.remaining().starts_with(s) {
+            return Ok(self.consume_bytes(s.len()));
+        }
+        Err(self.unexpected_token(s, &self.peek_token()))
+    }
+
+    pub fn one_of(&mut self, tags: &[&str]) -> Result<String, ParseError> {
+        match tags.iter().find(|t| self.remaining().starts_with(**t)) {
+            Some(t) => Ok(self.consume_bytes(t.len())),
+            None => Err(self.unexpected_token(&tags.join(" or "), &self.peek_token())),
+        }
+    }
+
+    pub fn integer<T: FromStr>(&mut self) -> Result<T, ParseError> where T::Err: Display {
+        let (line, column) = self.get_location();
+        let sign_length = if self.remaining().starts_with(['-', '+']) { 1 } else { 0 };
+        let n_digits = self.remaining()[sign_length..].chars().take_while(|c| c.is_ascii_digit()).count();
+        if n_digits == 0 {
+            return Err(self.unexpected_token("an integer", &self.peek_token()));
+        }
+        let to_return = parse_number(&self.remaining()[..(sign_length + n_digits)], column)
+            .map_err(|e| e.offset_lines(line - 1))?;
+        self.consume_bytes(sign_length + n_digits);
+        Ok(to_return)
+    }
+
+    pub fn word(&mut self) -> Result<String, ParseError> {
+        let n_chars = self.remaining().chars().take_while(|c| c.is_alphanumeric()).count();
+        if n_chars == 0 {
+            return Err(self.unexpected_token("a word", &self.peek_token()));
         }
+        Ok(self.consume_n(n_chars))
     }
 
+    pub fn optional<T>(&mut self, item: impl FnOnce(&mut Self) -> Result<T, ParseError>) -> Option<T> {
+        let start = (self.pointer, self.line, self.column);
+        match item(self) {
+            Ok(to_return) => Some(to_return),
+            Err(_) => {
+                (self.pointer, self.line, self.column) = start;
+                None
+            },
+        }
+    }
+
+    pub fn many<T>(&mut self, mut item: impl FnMut(&mut Self) -> Result<T, ParseError>) -> Vec<T> {
+        let mut to_return = Vec::new();
+        while let Some(parsed) = self.optional(&mut item) {
+            to_return.push(parsed);
+        }
+        to_return
+    }
+
+    pub fn sep_by<T>(&mut self, mut item: impl FnMut(&mut Self) -> Result<T, ParseError>, separator: &str) -> Result<Vec<T>, ParseError> {
+        let mut to_return = vec![item(self)?];
+        while let Some(parsed) = self.optional(|parser| {
+            parser.tag(separator)?;
+            item(parser)
+        }) {
+            to_return.push(parsed);
+        }
+        Ok(to_return)
+    }
+
+    pub fn line<T>(&mut self, item: impl FnOnce(&mut Self) -> Result<T, ParseError>) -> Result<T, ParseError> {
+        let to_return = item(self)?;
+        if self.n_remaining_to_parse() > 0 {
+            self.tag("\n").map_err(|_| self.unexpected_token("end of line", &self.peek_token()))?;
+        }
+        Ok(to_return)
+    }
+
+    pub fn lines<T>(&mut self, mut item: impl FnMut(&mut Self) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+        let mut to_return = Vec::new();
+        while self.n_remaining_to_parse() > 0 {
+            to_return.push(self.line(&mut item)?);
+        }
+        Ok(to_return)
+    }
+
+    fn peek_token(&self) -> String {
+        match self.remaining().split_whitespace().next() {
+            Some(token) => token.to_string(),
+            None => "end of input".to_string(),
+        }
+    }
+
+}
+
+#[cfg(test)]
+mod test_parser {
+    use super::*;
+
+    #[test]
+    fn test_parser_reports_location_of_unexpected_token() {
+        let mut parser = Parser::new("Sensor at x=2\nSensor at y=3".to_string());
+        parser.consume_line();
+        parser.expect("Sensor at ").unwrap();
+
+        let error = parser.expect("x=").unwrap_err();
+        assert_eq!(error.to_string(), "Line 2, column 11: expected \"x=\", but got \"y=\"");
+    }
+
+    #[test]
+    fn test_parser_expects_end_of_line_or_file() {
+        let mut parser = Parser::new("ab\ncd".to_string());
+        parser.consume_n(2);
+        assert!(parser.expect_end_of_line_or_file().is_ok());
+        parser.consume_n(1);
+
+        let error = parser.expect_end_of_line_or_file().unwrap_err();
+        assert_eq!(error.to_string(), "Line 2, column 2: expected \"end of line or file\", but got \"d\"");
+        parser.consume_remaining();
+        assert_eq!(parser.expect_end_of_line_or_file().unwrap(), "");
+    }
+
+    #[test]
+    fn test_parser_parses_integers() {
+        assert_eq!(Parser::parse_all("-12", |p| p.integer::<i32>()).unwrap(), -12);
+        assert_eq!(Parser::parse_all("+7", |p| p.integer::<i32>()).unwrap(), 7);
+        assert_eq!(Parser::parse_all("0190", |p| p.integer::<u32>()).unwrap(), 190);
+
+        let mut parser = Parser::new("x=-3, y=4".to_string());
+        parser.tag("x=").unwrap();
+        assert_eq!(parser.integer::<u32>().unwrap_err().to_string(), "Line 1, column 3: Unable to parse \"-3\" as a number: invalid digit found in string");
+        assert_eq!(parser.integer::<i64>().unwrap(), -3);
+        assert_eq!(parser.integer::<i64>().unwrap_err().to_string(), "Line 1, column 5: expected \"an integer\", but got \",\"");
+    }
+
+    #[test]
+    fn test_parser_backtracks_on_failure() {
+        let mut parser = Parser::new("tunnels lead to valves DD".to_string());
+        assert!(parser.tag("tunnel leads").is_err());
+        assert_eq!(parser.optional(|p| { p.tag("tunnels ")?; p.tag("leads") }), None);
+        assert_eq!(parser.one_of(&["tunnel leads to valve ", "tunnels lead to valves "]).unwrap(), "tunnels lead to valves ");
+        assert_eq!(parser.word().unwrap(), "DD");
+        assert!(parser.end_of_input().is_ok());
+    }
+
+    #[test]
+    fn test_parser_parses_lists() {
+        assert_eq!(Parser::parse_all("DD, II, BB", |p| p.sep_by(|p| p.word(), ", ")).unwrap(), vec!["DD", "II", "BB"]);
+        assert_eq!(Parser::parse_all("79, 98", |p| p.sep_by(|p| p.integer::<u32>(), ", ")).unwrap(), vec![79, 98]);
+        assert!(Parser::parse_all("79, 98,", |p| p.sep_by(|p| p.integer::<u32>(), ", ")).is_err());
+        assert_eq!(Parser::parse_all("[[[", |p| Ok(p.many(|p| p.tag("[")).len())).unwrap(), 3);
+        assert_eq!(Parser::parse_all("", |p| Ok(p.many(|p| p.tag("[")).len())).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_parser_parses_lines() {
+        let points = Parser::parse_all("1,2\n-3,4\n", |p| p.lines(|p| {
+            let x = p.integer::<i32>()?;
+            p.tag(",")?;
+            Ok((x, p.integer::<i32>()?))
+        })).unwrap();
+        assert_eq!(points, vec![(1, 2), (-3, 4)]);
+
+        let error = Parser::parse_all("1,2\n3,4 5", |p| p.lines(|p| {
+            let x = p.integer::<i32>()?;
+            p.tag(",")?;
+            Ok((x, p.integer::<i32>()?))
+        })).unwrap_err();
+        assert_eq!(error.to_string(), "Line 2, column 4: expected \"end of line\", but got \"5\"");
+    }
+
+    #[test]
+    fn test_parser_handles_multi_byte_characters() {
+        let mut parser = Parser::new("héllo wörld\nçà va".to_string());
+        assert_eq!(parser.consume_until_whitespace(), "héllo");
+        assert_eq!(parser.get_location(), (1, 6));
+
+        parser.consume_whitespace();
+        assert_eq!(parser.consume_n(2), "wö");
+        assert_eq!(parser.peek_char(), Some('r'));
+        assert_eq!(parser.consume_line(), "rld");
+        assert_eq!(parser.get_location(), (2, 1));
+
+        assert_eq!(parser.word().unwrap(), "çà");
+        assert_eq!(parser.get_column(), 3);
+        let error = parser.expect(" vä").unwrap_err();
+        assert_eq!(error.to_string(), "Line 2, column 3: expected \" vä\", but got \" va\"");
+        assert_eq!(parser.n_remaining_to_parse(), 0);
+    }
+
+    #[test]
+    fn test_parser_restores_location_when_backtracking() {
+        let mut parser = Parser::new("ab\ncd".to_string());
+        assert_eq!(parser.optional(|p| { p.tag("ab\nc")?; p.tag("x") }), None);
+        assert_eq!(parser.get_location(), (1, 1));
+        assert_eq!(parser.consume_n(10), "ab\ncd");
+        assert_eq!(parser.get_location(), (2, 3));
+    }
 }
@@ -0,0 +1,181 @@
+use std::{cmp::Reverse, collections::{BinaryHeap, HashMap, HashSet, VecDeque}, hash::Hash, ops::Add};
+
+// Follows each node back to its parent, so the path runs from whichever node has no parent to end.
+pub fn reconstruct_path<N: Clone + Eq + Hash>(parents: &HashMap<N, N>, end: N) -> Vec<N> {
+    let mut to_return = vec![end];
+    while let Some(parent) = parents.get(to_return.last().expect("The path always has at least one node.")) {
+        to_return.push(parent.clone());
+    }
+    to_return.reverse();
+    to_return
+}
+
+// The shortest path from start to the first node satisfying is_goal when every step costs the same, including both ends.
+pub fn bfs<N, I>(start: N, mut get_neighbors: impl FnMut(&N) -> I, mut is_goal: impl FnMut(&N) -> bool) -> Option<Vec<N>>
+where N: Clone + Eq + Hash, I: IntoIterator<Item = N> {
+    let mut parents = HashMap::new();
+    let mut seen = vec![start.clone()].into_iter().collect::<HashSet<_>>();
+    let mut to_check = vec![start].into_iter().collect::<VecDeque<_>>();
+    while let Some(node) = to_check.pop_front() {
+        if is_goal(&node) {
+            return Some(reconstruct_path(&parents, node));
+        }
+        for neighbor in get_neighbors(&node) {
+            if seen.insert(neighbor.clone()) {
+                parents.insert(neighbor.clone(), node.clone());
+                to_check.push_back(neighbor);
+            }
+        }
+    }
+    None
+}
+
+// The number of steps from start to every node that can be reached from it.
+pub fn bfs_distances<N, I>(start: N, mut get_neighbors: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where N: Clone + Eq + Hash, I: IntoIterator<Item = N> {
+    let mut distances = vec![(start.clone(), 0)].into_iter().collect::<HashMap<_, _>>();
+    let mut to_check = vec![start].into_iter().collect::<VecDeque<_>>();
+    while let Some(node) = to_check.pop_front() {
+        let distance = distances[&node] + 1;
+        for neighbor in get_neighbors(&node) {
+            if !distances.contains_key(&neighbor) {
+                distances.insert(neighbor.clone(), distance);
+                to_check.push_back(neighbor);
+            }
+        }
+    }
+    distances
+}
+
+// The cheapest path from start to the first node satisfying is_goal, along with its cost. Neighbors come with the
+// cost of stepping to them, which mustn't be negative.
+pub fn dijkstra<N, C, I>(start: N, get_neighbors: impl FnMut(&N) -> I, is_goal: impl FnMut(&N) -> bool) -> Option<(Vec<N>, C)>
+where N: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output = C>, I: IntoIterator<Item = (N, C)> {
+    a_star(start, get_neighbors, |_| C::default(), is_goal)
+}
+
+// Like dijkstra, but explores the nodes that look closest to a goal first. The path is only guaranteed to be the
+// cheapest if the heuristic never overestimates the remaining cost.
+pub fn a_star<N, C, I>(
+    start: N,
+    mut get_neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where N: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output = C>, I: IntoIterator<Item = (N, C)> {
+    let mut parents = HashMap::new();
+    let mut costs_so_far = vec![(start.clone(), C::default())].into_iter().collect::<HashMap<_, _>>();
+    // The heap only holds indices into pushed so that nodes don't need to be ordered. Ties go to the earliest pushed.
+    let mut to_check = BinaryHeap::new();
+    to_check.push((Reverse(heuristic(&start)), Reverse(0)));
+    let mut pushed = vec![(start, C::default())];
+    while let Some((_, Reverse(i))) = to_check.pop() {
+        let (node, cost) = pushed[i].clone();
+        if costs_so_far[&node] < cost {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some((reconstruct_path(&parents, node), cost));
+        }
+        for (neighbor, step_cost) in get_neighbors(&node) {
+            let new_cost = cost + step_cost;
+            if costs_so_far.get(&neighbor).is_none_or(|c| new_cost < *c) {
+                costs_so_far.insert(neighbor.clone(), new_cost);
+                parents.insert(neighbor.clone(), node.clone());
+                to_check.push((Reverse(new_cost + heuristic(&neighbor)), Reverse(pushed.len())));
+                pushed.push((neighbor, new_cost));
+            }
+        }
+    }
+    None
+}
+
+// The cheapest cost between every pair of nodes with a path between them, including from each node to itself.
+// get_cost gives the cost of the direct edge from one node to another, if there is one.
+pub fn floyd_warshall<N, C>(nodes: &[N], mut get_cost: impl FnMut(&N, &N) -> Option<C>) -> HashMap<(N, N), C>
+where N: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output = C> {
+    let n_nodes = nodes.len();
+    let mut costs = (0..n_nodes).map(
+        |i| (0..n_nodes).map(|j| if i == j { Some(C::default()) } else { get_cost(&nodes[i], &nodes[j]) }).collect::<Vec<_>>()
+    ).collect::<Vec<_>>();
+    // We go through the k's first so that we solve all k = 0, then k = 1, etc. recursively.
+    for k in 0..n_nodes {
+        for i in 0..n_nodes {
+            for j in 0..n_nodes {
+                if let (Some(via_i), Some(via_j)) = (costs[i][k], costs[k][j]) {
+                    let candidate = via_i + via_j;
+                    if costs[i][j].is_none_or(|c| candidate < c) {
+                        costs[i][j] = Some(candidate);
+                    }
+                }
+            }
+        }
+    }
+    costs.into_iter().enumerate().flat_map(
+        |(i, row)| row.into_iter().enumerate().filter_map(move |(j, cost)| cost.map(|c| ((i, j), c)))
+    ).map(|((i, j), c)| ((nodes[i].clone(), nodes[j].clone()), c)).collect()
+}
+
+#[cfg(test)]
+mod test_search {
+    use super::*;
+
+    // 0 -> 1 -> 2 -> 3 costs 3, while 0 -> 3 directly costs 5. 4 can't be reached from anywhere.
+    fn get_weighted_neighbors(node: &usize) -> Vec<(usize, u32)> {
+        match node {
+            0 => vec![(1, 1), (3, 5)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_reconstructs_paths() {
+        let parents = vec![(3, 2), (2, 1), (1, 0)].into_iter().collect();
+        assert_eq!(reconstruct_path(&parents, 3), vec![0, 1, 2, 3]);
+        assert_eq!(reconstruct_path(&parents, 0), vec![0]);
+    }
+
+    #[test]
+    fn test_bfs_finds_fewest_steps() {
+        let get_neighbors = |n: &usize| get_weighted_neighbors(n).into_iter().map(|(m, _)| m);
+        assert_eq!(bfs(0, get_neighbors, |n| *n == 3), Some(vec![0, 3]));
+        assert_eq!(bfs(0, get_neighbors, |n| *n == 0), Some(vec![0]));
+        assert_eq!(bfs(0, get_neighbors, |n| *n == 4), None);
+
+        let distances = bfs_distances(0, get_neighbors);
+        assert_eq!(distances.len(), 4);
+        assert_eq!(distances[&2], 2);
+        assert_eq!(distances[&3], 1);
+    }
+
+    #[test]
+    fn test_dijkstra_and_a_star_find_cheapest_path() {
+        assert_eq!(dijkstra(0, get_weighted_neighbors, |n| *n == 3), Some((vec![0, 1, 2, 3], 3)));
+        assert_eq!(dijkstra(1, get_weighted_neighbors, |n| *n == 0), None);
+        assert_eq!(a_star(0, get_weighted_neighbors, |n| 3 - (*n).min(3) as u32, |n| *n == 3), Some((vec![0, 1, 2, 3], 3)));
+
+        // Walking a 10 by 10 room from corner to corner.
+        let get_neighbors = |(x, y): &(i32, i32)| {
+            vec![(x + 1, *y), (x - 1, *y), (*x, y + 1), (*x, y - 1)].into_iter()
+                .filter(|(x, y)| (0..10).contains(x) && (0..10).contains(y))
+                .map(|v| (v, 1))
+        };
+        let (path, cost) = a_star((0, 0), get_neighbors, |(x, y)| (9 - x) + (9 - y), |v| *v == (9, 9)).unwrap();
+        assert_eq!(cost, 18);
+        assert_eq!(path.len(), 19);
+    }
+
+    #[test]
+    fn test_floyd_warshall_finds_all_pairs() {
+        let nodes = vec![0, 1, 2, 3, 4];
+        let costs = floyd_warshall(&nodes, |i, j| get_weighted_neighbors(i).into_iter().find(|(n, _)| n == j).map(|(_, c)| c));
+        assert_eq!(costs[&(0, 0)], 0);
+        assert_eq!(costs[&(0, 3)], 3);
+        assert_eq!(costs[&(1, 3)], 2);
+        assert_eq!(costs.get(&(3, 0)), None);
+        assert_eq!(costs.get(&(0, 4)), None);
+        assert_eq!(costs.len(), 5 + 3 + 2 + 1);
+    }
+}
@@ -1,12 +1,57 @@
+use std::array;
+use std::fmt::Debug;
+use std::hash::Hash;
 use std::ops::{Add, AddAssign, Sub, Mul};
 
+// The integer types a Vector2's or VecN's coordinates can have.
+pub trait Coordinate: Copy + Debug + Default + Hash + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    type Unsigned: Copy + Debug + Default + Add<Output = Self::Unsigned>;
+
+    fn one() -> Self;
+    fn abs(self) -> Self;
+    fn unsigned_abs(self) -> Self::Unsigned;
+    fn signum(self) -> Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn saturating_add(self, other: Self) -> Self;
+    fn saturating_sub(self, other: Self) -> Self;
+    fn saturating_mul(self, other: Self) -> Self;
+}
+
+macro_rules! impl_coordinate {
+    ($t:ty, $unsigned:ty) => {
+        impl Coordinate for $t {
+            type Unsigned = $unsigned;
+
+            fn one() -> Self { 1 }
+            fn abs(self) -> Self { <$t>::abs(self) }
+            fn unsigned_abs(self) -> Self::Unsigned { <$t>::unsigned_abs(self) }
+            fn signum(self) -> Self { <$t>::signum(self) }
+            fn checked_add(self, other: Self) -> Option<Self> { <$t>::checked_add(self, other) }
+            fn checked_sub(self, other: Self) -> Option<Self> { <$t>::checked_sub(self, other) }
+            fn checked_mul(self, other: Self) -> Option<Self> { <$t>::checked_mul(self, other) }
+            fn saturating_add(self, other: Self) -> Self { <$t>::saturating_add(self, other) }
+            fn saturating_sub(self, other: Self) -> Self { <$t>::saturating_sub(self, other) }
+            fn saturating_mul(self, other: Self) -> Self { <$t>::saturating_mul(self, other) }
+        }
+    };
+}
+
+impl_coordinate!(i32, u32);
+impl_coordinate!(i64, u64);
+
+// Most puzzles fit in i32, but ones with coordinates in the millions should use Vec2I64 so products don't overflow.
+pub type Vec2 = Vector2<i32>;
+pub type Vec2I64 = Vector2<i64>;
+
 #[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Vec2 {
-    x: i32,
-    y: i32,
+pub struct Vector2<T> {
+    x: T,
+    y: T,
 }
 
-impl Add for Vec2 {
+impl<T: Coordinate> Add for Vector2<T> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
@@ -17,7 +62,7 @@ impl Add for Vec2 {
     }
 }
 
-impl AddAssign for Vec2 {
+impl<T: Coordinate> AddAssign for Vector2<T> {
     fn add_assign(&mut self, other: Self) {
         *self = Self {
             x: self.x + other.x,
@@ -26,7 +71,7 @@ impl AddAssign for Vec2 {
     }
 }
 
-impl Sub for Vec2 {
+impl<T: Coordinate> Sub for Vector2<T> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
@@ -37,49 +82,92 @@ impl Sub for Vec2 {
     }
 }
 
-impl Mul<i32> for Vec2 {
+impl<T: Coordinate> Mul<T> for Vector2<T> {
     type Output = Self;
 
-    fn mul(self, rhs: i32) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         Self {
             x: rhs * self.x,
             y: rhs * self.y,
         }
     }
 }
-impl Vec2 {
 
-    pub fn new(x: i32, y: i32) -> Self {
+impl From<Vec2> for Vec2I64 {
+    fn from(v: Vec2) -> Self {
+        Self::new(v.x().into(), v.y().into())
+    }
+}
+
+impl TryFrom<Vec2I64> for Vec2 {
+    type Error = String;
+
+    fn try_from(v: Vec2I64) -> Result<Self, Self::Error> {
+        match (i32::try_from(v.x()), i32::try_from(v.y())) {
+            (Ok(x), Ok(y)) => Ok(Self::new(x, y)),
+            _ => Err(format!("{:?} doesn't fit in a Vec2", v)),
+        }
+    }
+}
+
+impl<T: Coordinate> Vector2<T> {
+
+    pub fn new(x: T, y: T) -> Self {
         Self {x, y}
     }
 
-    pub fn x(&self) -> i32 {
+    pub fn x(&self) -> T {
         self.x
     }
 
-    pub fn y(&self) -> i32 {
+    pub fn y(&self) -> T {
         self.y
     }
 
     pub fn i() -> Self {
-        Self::new(1, 0)
+        Self::new(T::one(), T::default())
     }
 
     pub fn j() -> Self {
-        Self::new(0, 1)
+        Self::new(T::default(), T::one())
     }
 
-    pub fn get_l1_norm(&self) -> u32 {
-        (self.x).abs() as u32 + (self.y).abs() as u32
+    pub fn get_l1_norm(&self) -> T::Unsigned {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
     }
 
     pub fn signum(&self) -> Self {
         Self::new(self.x.signum(), self.y.signum())
     }
 
+    pub fn checked_add(&self, other: Self) -> Option<Self> {
+        VecN::from(*self).checked_add(other.into()).map(Self::from)
+    }
+
+    pub fn checked_sub(&self, other: Self) -> Option<Self> {
+        VecN::from(*self).checked_sub(other.into()).map(Self::from)
+    }
+
+    pub fn checked_mul(&self, rhs: T) -> Option<Self> {
+        VecN::from(*self).checked_mul(rhs).map(Self::from)
+    }
+
+    pub fn saturating_add(&self, other: Self) -> Self {
+        VecN::from(*self).saturating_add(other.into()).into()
+    }
+
+    pub fn saturating_sub(&self, other: Self) -> Self {
+        VecN::from(*self).saturating_sub(other.into()).into()
+    }
+
+    pub fn saturating_mul(&self, rhs: T) -> Self {
+        VecN::from(*self).saturating_mul(rhs).into()
+    }
+
     pub fn get_points_between(left: Self, right: Self) -> Vec<Self> {
-        assert!(left.x() == right.x() || left.y() == right.y() || (left.x() - right.x()).abs() == (left.y() - right.y()).abs());
-        let direction = (right - left).signum();
+        let delta = right - left;
+        assert!(delta.x() == T::default() || delta.y() == T::default() || delta.x().abs() == delta.y().abs());
+        let direction = delta.signum();
         let mut to_return = Vec::new();
         let mut next = left;
         while next != right {
@@ -87,10 +175,218 @@ impl Vec2 {
             next += direction;
         }
         to_return.push(right);
-        return to_return;
+        to_return
+    }
+
+}
+
+// A vector with any number of integer coordinates, for puzzles that aren't flat.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct VecN<const N: usize, T = i32>([T; N]);
+
+pub type Vec3 = VecN<3>;
+
+impl<const N: usize, T: Coordinate> Default for VecN<N, T> {
+    fn default() -> Self {
+        Self([T::default(); N])
+    }
+}
+
+// The operators overflow just as T's do, so use the checked or saturating methods where that can happen.
+impl<const N: usize, T: Coordinate> Add for VecN<N, T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(array::from_fn(|i| self.0[i] + other.0[i]))
+    }
+}
+
+impl<const N: usize, T: Coordinate> AddAssign for VecN<N, T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const N: usize, T: Coordinate> Sub for VecN<N, T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(array::from_fn(|i| self.0[i] - other.0[i]))
+    }
+}
+
+impl<const N: usize, T: Coordinate> Mul<T> for VecN<N, T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self(self.0.map(|x| rhs * x))
+    }
+}
+
+impl<const N: usize, T: Coordinate> From<[T; N]> for VecN<N, T> {
+    fn from(coordinates: [T; N]) -> Self {
+        Self(coordinates)
+    }
+}
+
+impl<T: Coordinate> From<Vector2<T>> for VecN<2, T> {
+    fn from(v: Vector2<T>) -> Self {
+        Self([v.x(), v.y()])
+    }
+}
+
+impl<T: Coordinate> From<VecN<2, T>> for Vector2<T> {
+    fn from(v: VecN<2, T>) -> Self {
+        Self::new(v.get(0), v.get(1))
+    }
+}
+
+impl<const N: usize, T: Coordinate> VecN<N, T> {
+
+    pub fn from_coordinates(coordinates: [T; N]) -> Self {
+        Self(coordinates)
+    }
+
+    pub fn get(&self, i: usize) -> T {
+        self.0[i]
+    }
+
+    pub fn get_coordinates(&self) -> [T; N] {
+        self.0
+    }
+
+    // The unit vector along the ith axis.
+    pub fn unit(i: usize) -> Self {
+        assert!(i < N, "There is no axis {} in {} dimensions", i, N);
+        Self(array::from_fn(|j| if i == j { T::one() } else { T::default() }))
+    }
+
+    pub fn get_l1_norm(&self) -> T::Unsigned {
+        self.0.iter().fold(T::Unsigned::default(), |norm, x| norm + x.unsigned_abs())
+    }
+
+    pub fn signum(&self) -> Self {
+        Self(self.0.map(|x| x.signum()))
+    }
+
+    pub fn checked_add(&self, other: Self) -> Option<Self> {
+        Self::try_from_fn(|i| self.0[i].checked_add(other.0[i]))
+    }
+
+    pub fn checked_sub(&self, other: Self) -> Option<Self> {
+        Self::try_from_fn(|i| self.0[i].checked_sub(other.0[i]))
+    }
+
+    pub fn checked_mul(&self, rhs: T) -> Option<Self> {
+        Self::try_from_fn(|i| self.0[i].checked_mul(rhs))
+    }
+
+    pub fn saturating_add(&self, other: Self) -> Self {
+        Self(array::from_fn(|i| self.0[i].saturating_add(other.0[i])))
+    }
+
+    pub fn saturating_sub(&self, other: Self) -> Self {
+        Self(array::from_fn(|i| self.0[i].saturating_sub(other.0[i])))
+    }
+
+    pub fn saturating_mul(&self, rhs: T) -> Self {
+        Self(self.0.map(|x| x.saturating_mul(rhs)))
+    }
+
+    // Like array::from_fn, but None if any coordinate is.
+    fn try_from_fn(mut f: impl FnMut(usize) -> Option<T>) -> Option<Self> {
+        let mut overflowed = false;
+        let coordinates = array::from_fn(|i| f(i).unwrap_or_else(|| {
+            overflowed = true;
+            T::default()
+        }));
+        (!overflowed).then_some(Self(coordinates))
+    }
+
+    // Every coordinate that changes has to change by the same amount, as for Vec2's straight and diagonal lines.
+    pub fn get_points_between(left: Self, right: Self) -> Vec<Self> {
+        let delta = right - left;
+        let length = delta.0.iter().map(|x| x.abs()).max().unwrap_or_default();
+        assert!(
+            delta.0.iter().all(|x| *x == T::default() || x.abs() == length), "{:?} and {:?} aren't on a straight line", left, right
+        );
+        let direction = delta.signum();
+        let mut to_return = vec![left];
+        let mut next = left;
+        while next != right {
+            next += direction;
+            to_return.push(next);
+        }
+        to_return
+    }
+
+    // The offsets to the surrounding vectors where exactly n_changed coordinates differ by one.
+    pub fn get_offsets(n_changed: usize) -> Vec<Self> {
+        let steps = [T::default().saturating_sub(T::one()), T::default(), T::one()];
+        (0..3_usize.pow(N as u32))
+            .map(|code| Self(array::from_fn(|i| steps[code / 3_usize.pow(i as u32) % 3])))
+            .filter(|offset| offset.0.iter().filter(|x| **x != T::default()).count() == n_changed)
+            .collect()
+    }
+
+    // In 3D, the cubes sharing a face with this one.
+    pub fn get_face_neighbors(&self) -> Vec<Self> {
+        self.get_neighbors_changing(1)
+    }
+
+    // In 3D, the cubes sharing only an edge with this one.
+    pub fn get_edge_neighbors(&self) -> Vec<Self> {
+        self.get_neighbors_changing(2)
+    }
+
+    // The vectors differing by one in every coordinate, which in 3D share only a corner.
+    pub fn get_corner_neighbors(&self) -> Vec<Self> {
+        self.get_neighbors_changing(N)
+    }
+
+    // Everything within one step along each axis.
+    pub fn get_all_neighbors(&self) -> Vec<Self> {
+        (1..=N).flat_map(|n| self.get_neighbors_changing(n)).collect()
+    }
+
+    fn get_neighbors_changing(&self, n_changed: usize) -> Vec<Self> {
+        Self::get_offsets(n_changed).into_iter().map(|offset| *self + offset).collect()
+    }
+
+}
+
+impl<T: Coordinate> VecN<3, T> {
+
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self([x, y, z])
+    }
+
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+
+    pub fn z(&self) -> T {
+        self.0[2]
+    }
+
+    pub fn i() -> Self {
+        Self::unit(0)
+    }
+
+    pub fn j() -> Self {
+        Self::unit(1)
+    }
+
+    pub fn k() -> Self {
+        Self::unit(2)
     }
 
 }
+
 #[cfg(test)]
 mod test_vector {
     use super::*;
@@ -138,4 +434,114 @@ mod test_vector {
             vec![Vec2::new(1, 2), Vec2::new(0, 3), Vec2::new(-1, 4)]
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_vectors_report_overflow() {
+        let big = Vec2::new(i32::MAX, 0);
+        assert_eq!(big.checked_add(Vec2::i()), None);
+        assert_eq!(big.checked_sub(Vec2::i()), Some(Vec2::new(i32::MAX - 1, 0)));
+        assert_eq!(Vec2::new(-i32::MAX, 0).checked_sub(Vec2::i() * 2), None);
+        assert_eq!(Vec2::new(1 << 20, -3).checked_mul(1 << 12), None);
+        assert_eq!(big.saturating_add(Vec2::new(1, -1)), Vec2::new(i32::MAX, -1));
+        assert_eq!(Vec2::new(1 << 20, -(1 << 20)).saturating_mul(1 << 12), Vec2::new(i32::MAX, i32::MIN));
+    }
+
+    #[test]
+    fn test_i64_vectors_hold_large_coordinates() {
+        let v = Vec2I64::new(3_000_000, -2_000_000) * 4_000_000;
+        assert_eq!(v, Vec2I64::new(12_000_000_000_000, -8_000_000_000_000));
+        assert_eq!(v.get_l1_norm(), 20_000_000_000_000_u64);
+        assert_eq!(Vec2I64::new(i64::MAX, 0).checked_add(Vec2I64::i()), None);
+        assert_eq!(
+            Vec2I64::get_points_between(Vec2I64::new(5_000_000_000, 1), Vec2I64::new(5_000_000_002, 3)),
+            vec![Vec2I64::new(5_000_000_000, 1), Vec2I64::new(5_000_000_001, 2), Vec2I64::new(5_000_000_002, 3)]
+        );
+
+        assert_eq!(Vec2I64::from(Vec2::new(-7, 9)), Vec2I64::new(-7, 9));
+        assert_eq!(Vec2::try_from(Vec2I64::new(-7, 9)), Ok(Vec2::new(-7, 9)));
+        assert!(Vec2::try_from(v).is_err());
+    }
+
+    #[test]
+    fn test_vecns_report_overflow() {
+        let big = Vec3::new(0, i32::MAX, 0);
+        assert_eq!(big.checked_add(Vec3::j()), None);
+        assert_eq!(big.checked_sub(Vec3::j()), Some(Vec3::new(0, i32::MAX - 1, 0)));
+        assert_eq!(Vec3::new(1 << 20, 0, -3).checked_mul(1 << 12), None);
+        assert_eq!(big.saturating_add(Vec3::new(-1, 1, 1)), Vec3::new(-1, i32::MAX, 1));
+        assert_eq!(Vec3::new(1 << 20, -(1 << 20), 1).saturating_mul(1 << 12), Vec3::new(i32::MAX, i32::MIN, 1 << 12));
+
+        let v = VecN::<3, i64>::new(3_000_000, 0, -2_000_000) * 4_000_000;
+        assert_eq!(v.get_coordinates(), [12_000_000_000_000, 0, -8_000_000_000_000]);
+        assert_eq!(v.get_l1_norm(), 20_000_000_000_000_u64);
+    }
+
+    #[test]
+    fn test_vec3s_do_arithmetic() {
+        let x = Vec3::new(1, -2, 3);
+        let y = Vec3::new(3, 5, -1);
+        assert_eq!(x + y, Vec3::new(4, 3, 2));
+        assert_eq!(x - y, Vec3::new(-2, -7, 4));
+        assert_eq!(x * 2, Vec3::new(2, -4, 6));
+        assert_eq!(Vec3::i() + Vec3::j() + Vec3::k(), Vec3::new(1, 1, 1));
+        assert_eq!((x.x(), x.y(), x.z()), (1, -2, 3));
+
+        let mut z = x;
+        z += y;
+        assert_eq!(z, x + y);
+    }
+
+    #[test]
+    fn test_vecns_agree_with_vec2() {
+        let pairs = vec![
+            (Vec2::new(0, 0), Vec2::new(0, 0)),
+            (Vec2::new(1, 5), Vec2::new(3, 5)),
+            (Vec2::new(1, 2), Vec2::new(1, -1)),
+            (Vec2::new(1, 2), Vec2::new(-1, 4)),
+        ];
+        for (left, right) in pairs {
+            let (left_n, right_n) = (VecN::<2>::from(left), VecN::<2>::from(right));
+            assert_eq!(Vec2::from(left_n + right_n), left + right);
+            assert_eq!(Vec2::from(left_n - right_n), left - right);
+            assert_eq!(Vec2::from(left_n * -3), left * -3);
+            assert_eq!(left_n.get_l1_norm(), left.get_l1_norm());
+            assert_eq!(Vec2::from(left_n.signum()), left.signum());
+            assert_eq!(
+                VecN::get_points_between(left_n, right_n).into_iter().map(Vec2::from).collect::<Vec<_>>(),
+                Vec2::get_points_between(left, right)
+            );
+        }
+    }
+
+    #[test]
+    fn test_vecns_get_norm_signum_and_points_between() {
+        assert_eq!(Vec3::new(-2, 1, 4).get_l1_norm(), 7);
+        assert_eq!(Vec3::new(-2, 0, 4).signum(), Vec3::new(-1, 0, 1));
+        assert_eq!(VecN::from([5, -5, 0, 2]).signum(), VecN::from([1, -1, 0, 1]));
+        assert_eq!(
+            Vec3::get_points_between(Vec3::new(1, 1, 1), Vec3::new(3, 1, -1)),
+            vec![Vec3::new(1, 1, 1), Vec3::new(2, 1, 0), Vec3::new(3, 1, -1)]
+        );
+        assert_eq!(Vec3::get_points_between(Vec3::k(), Vec3::k()), vec![Vec3::k()]);
+    }
+
+    #[test]
+    fn test_vecns_get_neighbors() {
+        let origin = Vec3::default();
+        assert_eq!(origin.get_face_neighbors().len(), 6);
+        assert_eq!(origin.get_edge_neighbors().len(), 12);
+        assert_eq!(origin.get_corner_neighbors().len(), 8);
+        assert_eq!(origin.get_all_neighbors().len(), 26);
+        assert!(origin.get_face_neighbors().iter().all(|v| v.get_l1_norm() == 1));
+        assert!(origin.get_edge_neighbors().iter().all(|v| v.get_l1_norm() == 2));
+        assert!(origin.get_corner_neighbors().contains(&Vec3::new(-1, 1, -1)));
+
+        let v = Vec3::new(2, 2, 5);
+        assert!(v.get_face_neighbors().contains(&Vec3::new(2, 2, 6)));
+        assert!(!v.get_face_neighbors().contains(&v));
+
+        assert_eq!(VecN::<2>::default().get_face_neighbors().len(), 4);
+        assert_eq!(VecN::<2>::default().get_all_neighbors().len(), 8);
+        assert_eq!(VecN::<4>::default().get_all_neighbors().len(), 80);
+    }
+}